@@ -1,76 +1,290 @@
+/// Whether the toolchain accepts the `asm_experimental_arch` feature, which `js!` needs to export
+/// `EM_JS` symbols with inline assembly. Compiling a probe also honors `RUSTC_BOOTSTRAP` the way
+/// rustc does, including values such as `0`, `-1` or a crate name.
+fn is_nightly() -> bool {
+    let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    let probe = out_dir.join("probe_asm_experimental_arch.rs");
+    std::fs::write(&probe, "#![feature(asm_experimental_arch)]\n").expect("could not write probe");
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    std::process::Command::new(rustc)
+        .args([
+            "--crate-name",
+            "emscripten_rs_sys",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(&probe)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// The link settings needed by the API families enabled with cargo features.
+fn feature_link_settings() -> emscripten_rs_build::LinkSettings {
+    let mut settings = emscripten_rs_build::LinkSettings::new();
+    if cfg!(feature = "asyncify") {
+        settings = settings.asyncify(true);
+    }
+    if cfg!(feature = "audio_worklet") {
+        settings = settings.audio_worklet(true);
+    }
+    if cfg!(feature = "fetch") {
+        settings = settings.fetch(true);
+    }
+    if cfg!(feature = "pthreads") {
+        settings = settings.pthreads(true);
+    }
+    if cfg!(feature = "wasm_workers") {
+        settings = settings.wasm_workers(true);
+    }
+    settings
+}
+
+/// Writes the static holding the settings in the link argument section, which `src/lib.rs`
+/// includes.
+fn write_link_args_section(settings: &emscripten_rs_build::LinkSettings) {
+    let metadata = settings.metadata();
+    let code = if metadata.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "#[used]\n#[unsafe(link_section = {:?})]\nstatic LINK_ARGS: [u8; {}] = {:?};\n",
+            emscripten_rs_build::link::LINK_ARGS_SECTION,
+            metadata.len(),
+            metadata.as_bytes(),
+        )
+    };
+    let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("link_args.rs"), code).expect("could not write the link arguments");
+}
+
+/// Generates `src/binding`, with one module per header of `sysroot/include/emscripten`.
+#[cfg(feature = "build_bindings")]
+mod bindings {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Headers that only declare C++ templates.
+    const EXCLUDED_HEADERS: [&str; 2] = ["bind.h", "wire.h"];
+
+    /// Items of other headers that the single binding module used to match by name, kept in the
+    /// `external` module so that code using them still compiles: the semaphore limits of the musl
+    /// `limits.h`, and a libc++ availability macro.
+    const LEGACY_ITEMS: &str = "SEM_NSEMS_MAX|SEM_VALUE_MAX|_POSIX_SEM_NSEMS_MAX|\
+                                _POSIX_SEM_VALUE_MAX|_LIBCPP_AVAILABILITY_HAS_FILESYSTEM_LIBRARY";
+
+    /// Collects the headers below `dir`, including the ones of its subdirectories.
+    fn collect_headers(dir: &Path, headers: &mut Vec<PathBuf>) {
+        let mut entries = fs::read_dir(dir)
+            .expect("include directory not found")
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .collect::<Vec<_>>();
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                collect_headers(&path, headers);
+            } else if path.extension().is_some_and(|extension| extension == "h")
+                && !EXCLUDED_HEADERS
+                    .iter()
+                    .any(|excluded| path.ends_with(excluded))
+            {
+                headers.push(path);
+            }
+        }
+    }
+
+    /// The module of a header, e.g. `html5_webgl` for `emscripten/html5_webgl.h`.
+    fn module_name(include_dir: &Path, header: &Path) -> String {
+        header
+            .strip_prefix(include_dir)
+            .unwrap()
+            .with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_str().unwrap().replace('-', "_"))
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn escape_regex(text: &str) -> String {
+        let mut escaped = String::new();
+        for c in text.chars() {
+            if "\\.+*?()|[]{}^$".contains(c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    fn builder(sysroot: &Path) -> bindgen::Builder {
+        bindgen::Builder::default()
+            .clang_arg("-x")
+            .clang_arg("c++")
+            .clang_arg("-std=c++20")
+            .clang_arg("-Wno-nullability-completeness")
+            .clang_arg("-Wno-return-type-c-linkage")
+            .clang_arg("-Wno-pragma-once-outside-header")
+            .clang_arg("-fvisibility=default") // https://github.com/rust-lang/rust-bindgen/issues/2989
+            .clang_arg(format!("--sysroot={}", sysroot.display()))
+            .clang_arg(format!("-I{}", sysroot.join("include").display()))
+            .clang_arg(format!(
+                "-I{}",
+                sysroot.join("include").join("compat").display()
+            ))
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+            .wrap_unsafe_ops(true)
+            .blocklist_item("std::.*")
+            .raw_line("use super::*;")
+            .derive_default(true)
+    }
+
+    /// Writes the module if the header declared anything, and returns whether it did.
+    fn write_module(builder: bindgen::Builder, out_dir: &Path, module: &str) -> bool {
+        let code = builder
+            .generate()
+            .expect("Binding generation failed")
+            .to_string();
+        if !code.contains("pub ") {
+            return false;
+        }
+        fs::write(out_dir.join(format!("{module}.rs")), code)
+            .expect("Could not write binding to file");
+        true
+    }
+
+    /// The names of the public items of the modules in `dir`.
+    fn item_names(dir: &Path) -> Vec<String> {
+        const KINDS: [&str; 7] = ["const", "fn", "static", "struct", "type", "union", "enum"];
+
+        let mut names = Vec::new();
+        for entry in fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+        {
+            let code = fs::read_to_string(entry.path()).unwrap_or_default();
+            for line in code.lines() {
+                let mut words = line.split_whitespace();
+                if words.next() == Some("pub")
+                    && words.next().is_some_and(|kind| KINDS.contains(&kind))
+                    && let Some(name) = words.next()
+                {
+                    let name = name
+                        .split(|c: char| !c.is_alphanumeric() && c != '_')
+                        .next();
+                    names.extend(name.map(str::to_string));
+                }
+            }
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Generates the modules into a staging directory next to `out_dir`, and replaces `out_dir`
+    /// with it only once every module was generated, so a failing header keeps the old bindings.
+    pub fn generate(sysroot: &Path, out_dir: &Path) {
+        let include_dir = sysroot.join("include").join("emscripten");
+        let mut headers = Vec::new();
+        collect_headers(&include_dir, &mut headers);
+
+        let staging = out_dir.with_extension("new");
+        if staging.exists() {
+            fs::remove_dir_all(&staging).expect("Could not remove the staging directory");
+        }
+        fs::create_dir_all(&staging).expect("Could not create the staging directory");
+
+        let mut modules = Vec::new();
+        for header in &headers {
+            println!("{}", header.display());
+            let module = module_name(&include_dir, header);
+            // Items used from other headers resolve through the `use super::*` of each module.
+            let builder = builder(sysroot)
+                .header(header.to_str().unwrap())
+                .allowlist_file(escape_regex(header.to_str().unwrap()))
+                .allowlist_recursively(false);
+            if write_module(builder, &staging, &module) {
+                modules.push(module);
+            }
+        }
+
+        // The items of other headers that the emscripten headers refer to, such as `FILE`,
+        // `pthread_attr_t`, the `sys/socket.h` types or the webgpu handles, go to the `external`
+        // module. Blocklisted items are still traversed, so this only keeps their dependencies.
+        let include_pattern = format!("{}/.*", escape_regex(include_dir.to_str().unwrap()));
+        let external = builder(sysroot)
+            .headers(headers.iter().map(|header| header.to_str().unwrap()))
+            .allowlist_file(&include_pattern)
+            .blocklist_file(&include_pattern)
+            .allowlist_item(LEGACY_ITEMS);
+        if write_module(external, &staging, "external") {
+            modules.push("external".to_string());
+        }
+
+        modules.sort();
+        let mut root = String::from(
+            "/* automatically generated by build.rs, one module per emscripten header */\n\n\
+             #![allow(non_camel_case_types)]\n\
+             #![allow(non_snake_case)]\n\
+             #![allow(non_upper_case_globals)]\n\
+             #![allow(unused_imports)]\n\n",
+        );
+        for module in &modules {
+            root.push_str(&format!("pub mod {module};\npub use {module}::*;\n"));
+        }
+        fs::write(staging.join("mod.rs"), root).expect("Could not write binding to file");
+
+        // Items can disappear with a new SDK, but also when the allowlists miss them.
+        let generated = item_names(&staging);
+        let missing = item_names(out_dir)
+            .into_iter()
+            .filter(|name| generated.binary_search(name).is_err())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            println!(
+                "cargo:warning=items missing from the regenerated bindings: {}",
+                missing.join(", ")
+            );
+        }
+
+        if out_dir.exists() {
+            fs::remove_dir_all(out_dir).expect("Could not remove the old bindings");
+        }
+        fs::rename(&staging, out_dir).expect("Could not move the new bindings in place");
+    }
+}
+
 fn main() {
+    // `rustc-link-arg` only reaches the targets of this package. The final artifact gets the
+    // settings from the link argument section when it is linked with `emcc-rs`, and otherwise
+    // dependent packages forward them with `LinkSettings::from_dependency("emscripten")`.
+    let settings = feature_link_settings();
+    settings.emit();
+    settings.emit_metadata();
+    write_link_args_section(&settings);
+
+    println!("cargo::rustc-check-cfg=cfg(em_js_nightly)");
+    println!("cargo:rerun-if-env-changed=RUSTC_BOOTSTRAP");
+    // Stable toolchains store `js!` functions in a custom section instead, which the `emcc-rs`
+    // linker of emscripten-rs-build turns into a JS library.
+    if is_nightly() {
+        println!("cargo:rustc-cfg=em_js_nightly");
+    }
+
     #[cfg(feature = "build_bindings")]
     {
-        use std::fs::{self, FileType};
-        use which::which;
-
         println!("cargo:rerun-if-env-changed=PATH");
-        if let Ok(compiler) = which("emcc") {
+        if let Ok(compiler) = which::which("emcc") {
             let sysroot = compiler.parent().unwrap().join("cache").join("sysroot");
-            let excluded_headers = ["wire.h"];
-
-            let headers = fs::read_dir(sysroot.join("include").join("emscripten"))
-                .expect("include directory not found")
-                .filter_map(|e| e.ok())
-                .filter(|e| {
-                    e.file_type()
-                        .as_ref()
-                        .map(FileType::is_file)
-                        .unwrap_or(false)
-                })
-                .filter_map(|e| {
-                    let path = e.path();
-                    let is_header = path
-                        .extension()
-                        .map(|extension| extension == "h")
-                        .unwrap_or(false);
-                    if is_header
-                        && !excluded_headers
-                            .iter()
-                            .any(|excluded| path.ends_with(excluded))
-                    {
-                        println!("{}", path.display());
-                        Some(path.to_str().unwrap().to_string())
-                    } else {
-                        None
-                    }
-                });
-
-            bindgen::Builder::default()
-                .headers(headers)
-                .clang_arg("-x")
-                .clang_arg("c++")
-                .clang_arg("-std=c++20")
-                .clang_arg("-Wno-nullability-completeness")
-                .clang_arg("-Wno-return-type-c-linkage")
-                .clang_arg("-Wno-pragma-once-outside-header")
-                .clang_arg("-fvisibility=default") // https://github.com/rust-lang/rust-bindgen/issues/2989
-                .clang_arg(format!("--sysroot={}", sysroot.display()))
-                .clang_arg(format!("-I{}", sysroot.join("include").display()))
-                .clang_arg(format!(
-                    "-I{}",
-                    sysroot.join("include").join("compat").display()
-                ))
-                .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-                .wrap_unsafe_ops(true)
-                .allowlist_file(format!("{}/emscripten/.*", sysroot.display()))
-                .allowlist_item(".*_?emscripten_.*")
-                .allowlist_item(".*_?EMSCRIPTEN_.*")
-                .allowlist_item(".*_?emval_.*")
-                .allowlist_item(".*_?EMVAL_.*")
-                .allowlist_item(".*_?em_.*")
-                .allowlist_item(".*_?EM_.*")
-                .blocklist_item("std::.*")
-                .raw_line("#![allow(non_camel_case_types)]")
-                .raw_line("#![allow(non_snake_case)]")
-                .raw_line("#![allow(non_upper_case_globals)]")
-                .derive_default(true)
-                .generate()
-                .expect("Binding generation failed")
-                .write_to_file("src/binding.rs")
-                .expect("Could not write binding to file");
-
-            println!("cargo:rustc-link-arg-examples=-sEXPORTED_RUNTIME_METHODS=['webSockets']");
+            bindings::generate(&sysroot, std::path::Path::new("src/binding"));
+
+            emscripten_rs_build::LinkSettings::new()
+                .exported_runtime_methods(["webSockets"])
+                .emit_for(&emscripten_rs_build::LinkTarget::Examples);
         } else {
             println!("cargo:warning=emcc not found, falling back to prebuilt bindings");
         }
@@ -0,0 +1,3 @@
+fn main() -> std::process::ExitCode {
+    emscripten_rs_build::link::main()
+}
@@ -0,0 +1,443 @@
+//! Typed emscripten link settings for build scripts.
+//!
+//! ```no_run
+//! use emscripten_rs_build::{Environment, LinkSettings};
+//!
+//! // In build.rs
+//! LinkSettings::new()
+//!     .allow_memory_growth(true)
+//!     .environment([Environment::Web, Environment::Worker])
+//!     .exported_runtime_methods(["ccall", "UTF8ToString"])
+//!     .emit();
+//! ```
+//!
+//! See the [settings reference](https://emscripten.org/docs/tools_reference/settings_reference.html)
+//! for what each setting does.
+//!
+//! On stable toolchains, the `emcc-rs` binary of this crate has to be used as the linker, see
+//! [`link`].
+
+use std::fmt;
+
+pub mod link;
+
+/// A JS environment the generated code can run in, for `-sENVIRONMENT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Web,
+    Webview,
+    Worker,
+    Node,
+    Shell,
+}
+
+impl Environment {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Web => "web",
+            Self::Webview => "webview",
+            Self::Worker => "worker",
+            Self::Node => "node",
+            Self::Shell => "shell",
+        }
+    }
+}
+
+/// The cargo targets link arguments are passed to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// Every target of the package, with `cargo:rustc-link-arg`.
+    All,
+    /// Binaries, with `cargo:rustc-link-arg-bins`.
+    Bins,
+    /// A single binary, with `cargo:rustc-link-arg-bin`.
+    Bin(String),
+    /// Examples, with `cargo:rustc-link-arg-examples`.
+    Examples,
+    /// Tests, with `cargo:rustc-link-arg-tests`.
+    Tests,
+    /// Benchmarks, with `cargo:rustc-link-arg-benches`.
+    Benches,
+    /// The `cdylib` target, with `cargo:rustc-link-arg-cdylib`.
+    Cdylib,
+}
+
+/// The value of a `-s` setting.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Bool(bool),
+    Int(u64),
+    /// Emitted as is, e.g. a JS expression.
+    Raw(String),
+    List(Vec<String>),
+}
+
+impl Value {
+    /// Reads a value written by `Display`, keeping anything but lists as is.
+    fn parse(text: &str) -> Self {
+        let Some(items) = text
+            .strip_prefix('[')
+            .and_then(|text| text.strip_suffix(']'))
+        else {
+            return Self::Raw(text.to_string());
+        };
+        let mut list = Vec::new();
+        let mut chars = items.chars();
+        while let Some(c) = chars.next() {
+            if c != '\'' {
+                continue;
+            }
+            let mut item = String::new();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => item.extend(chars.next()),
+                    '\'' => break,
+                    c => item.push(c),
+                }
+            }
+            list.push(item);
+        }
+        Self::List(list)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", *value as u8),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Raw(value) => f.write_str(value),
+            Self::List(items) => {
+                f.write_str("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "'{}'", item.replace('\\', "\\\\").replace('\'', "\\'"))?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// A set of emscripten link settings, emitted as `cargo:rustc-link-arg` lines from a build script.
+///
+/// Setting the same option twice keeps the last value, and list settings accumulate.
+#[derive(Debug, Clone, Default)]
+pub struct LinkSettings {
+    settings: Vec<(String, Value)>,
+    pthreads: bool,
+}
+
+impl LinkSettings {
+    /// The separator of the arguments in the published metadata, which cannot appear in an
+    /// argument the way spaces can, e.g. in `-sEXPORT_NAME`.
+    pub const SEPARATOR: char = '\x1f';
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The settings a dependency with the `links = "<links>"` key published with
+    /// [`LinkSettings::emit_metadata`]. Cargo only passes them to the build scripts of packages
+    /// depending directly on it.
+    ///
+    /// ```no_run
+    /// // The build script of a crate depending on emscripten-rs-sys, which links "emscripten"
+    /// emscripten_rs_build::LinkSettings::from_dependency("emscripten")
+    ///     .allow_memory_growth(true)
+    ///     .emit();
+    /// ```
+    pub fn from_dependency(links: &str) -> Self {
+        let var = format!("DEP_{}_LINK_ARGS", links.to_uppercase().replace('-', "_"));
+        println!("cargo:rerun-if-env-changed={var}");
+        std::env::var(&var)
+            .map(|args| Self::from_args(args.split(Self::SEPARATOR)))
+            .unwrap_or_default()
+    }
+
+    fn from_args<'a>(args: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut settings = Self::new();
+        for arg in args {
+            if arg == "-pthread" {
+                settings.pthreads = true;
+            } else if let Some((name, value)) =
+                arg.strip_prefix("-s").and_then(|arg| arg.split_once('='))
+            {
+                settings = settings.set(name, Value::parse(value));
+            }
+        }
+        settings
+    }
+
+    fn set(mut self, name: &str, value: Value) -> Self {
+        match self
+            .settings
+            .iter_mut()
+            .find(|(setting, _)| setting == name)
+        {
+            Some((_, old)) => *old = value,
+            None => self.settings.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    fn extend<I, S>(self, name: &str, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut list = match self.settings.iter().find(|(setting, _)| setting == name) {
+            Some((_, Value::List(list))) => list.clone(),
+            _ => Vec::new(),
+        };
+        for item in items {
+            let item = item.into();
+            if !list.contains(&item) {
+                list.push(item);
+            }
+        }
+        self.set(name, Value::List(list))
+    }
+
+    /// `-sALLOW_MEMORY_GROWTH`: lets the wasm memory grow beyond its initial size.
+    pub fn allow_memory_growth(self, enabled: bool) -> Self {
+        self.set("ALLOW_MEMORY_GROWTH", Value::Bool(enabled))
+    }
+
+    /// `-sINITIAL_MEMORY`: the initial size of the wasm memory in bytes, a multiple of 64KiB.
+    pub fn initial_memory(self, bytes: u64) -> Self {
+        self.set("INITIAL_MEMORY", Value::Int(bytes))
+    }
+
+    /// `-sMAXIMUM_MEMORY`: the size in bytes the wasm memory can grow to.
+    pub fn maximum_memory(self, bytes: u64) -> Self {
+        self.set("MAXIMUM_MEMORY", Value::Int(bytes))
+    }
+
+    /// `-sSTACK_SIZE`: the size of the stack of the main thread in bytes.
+    pub fn stack_size(self, bytes: u64) -> Self {
+        self.set("STACK_SIZE", Value::Int(bytes))
+    }
+
+    /// `-sASYNCIFY`: lets synchronous calls such as `emscripten_sleep` suspend the wasm stack.
+    pub fn asyncify(self, enabled: bool) -> Self {
+        self.set("ASYNCIFY", Value::Bool(enabled))
+    }
+
+    /// `-sASYNCIFY_IMPORTS`: additional JS imports that may suspend the wasm stack, e.g.
+    /// `env.fetch_sync`.
+    pub fn asyncify_imports<I, S>(self, imports: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extend("ASYNCIFY_IMPORTS", imports)
+    }
+
+    /// `-sASYNCIFY_STACK_SIZE`: the size in bytes of the buffer the unwound stack is saved to.
+    pub fn asyncify_stack_size(self, bytes: u64) -> Self {
+        self.set("ASYNCIFY_STACK_SIZE", Value::Int(bytes))
+    }
+
+    /// `-pthread`: links with pthread support. The crate itself must also be compiled with the
+    /// `atomics` and `bulk-memory` target features.
+    pub fn pthreads(mut self, enabled: bool) -> Self {
+        self.pthreads = enabled;
+        self
+    }
+
+    /// `-sPTHREAD_POOL_SIZE`: the number of workers started before `main` for pthreads.
+    pub fn pthread_pool_size(self, size: u32) -> Self {
+        self.set("PTHREAD_POOL_SIZE", Value::Int(size.into()))
+    }
+
+    /// `-sPTHREAD_POOL_SIZE` computed at startup by a JS expression, e.g.
+    /// `navigator.hardwareConcurrency`.
+    pub fn pthread_pool_size_expr(self, expr: impl Into<String>) -> Self {
+        self.set("PTHREAD_POOL_SIZE", Value::Raw(expr.into()))
+    }
+
+    /// `-sEXPORTED_RUNTIME_METHODS`: runtime functions exported on the `Module` object.
+    pub fn exported_runtime_methods<I, S>(self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extend("EXPORTED_RUNTIME_METHODS", methods)
+    }
+
+    /// `-sENVIRONMENT`: the JS environments the generated code supports.
+    pub fn environment(self, environments: impl IntoIterator<Item = Environment>) -> Self {
+        let names = environments
+            .into_iter()
+            .map(Environment::name)
+            .collect::<Vec<_>>();
+        self.set("ENVIRONMENT", Value::Raw(names.join(",")))
+    }
+
+    /// `-sMODULARIZE`: wraps the generated JS in a factory function returning a promise of the
+    /// module.
+    pub fn modularize(self, enabled: bool) -> Self {
+        self.set("MODULARIZE", Value::Bool(enabled))
+    }
+
+    /// `-sEXPORT_NAME`: the name of the global, or of the factory function with `MODULARIZE`.
+    pub fn export_name(self, name: impl Into<String>) -> Self {
+        self.set("EXPORT_NAME", Value::Raw(name.into()))
+    }
+
+    /// `-sFETCH`: enables the `emscripten_fetch` API.
+    pub fn fetch(self, enabled: bool) -> Self {
+        self.set("FETCH", Value::Bool(enabled))
+    }
+
+    /// `-sWASM_WORKERS`: enables the wasm workers API.
+    pub fn wasm_workers(self, enabled: bool) -> Self {
+        self.set("WASM_WORKERS", Value::Bool(enabled))
+    }
+
+    /// `-sAUDIO_WORKLET`: enables the Web Audio worklet API. Enabling it also enables wasm
+    /// workers, which audio worklets are built on.
+    pub fn audio_worklet(self, enabled: bool) -> Self {
+        let settings = self.set("AUDIO_WORKLET", Value::Bool(enabled));
+        if enabled {
+            settings.wasm_workers(true)
+        } else {
+            settings
+        }
+    }
+
+    /// Any other `-s` setting, with its value written as emcc expects it, e.g.
+    /// `.setting("EXIT_RUNTIME", "1")`.
+    pub fn setting(self, name: &str, value: impl Into<String>) -> Self {
+        self.set(name, Value::Raw(value.into()))
+    }
+
+    /// The linker arguments, e.g. `-sALLOW_MEMORY_GROWTH=1`.
+    pub fn args(&self) -> Vec<String> {
+        let pthread = self.pthreads.then(|| "-pthread".to_string());
+        pthread
+            .into_iter()
+            .chain(
+                self.settings
+                    .iter()
+                    .map(|(name, value)| format!("-s{name}={value}")),
+            )
+            .collect()
+    }
+
+    /// The linker arguments joined by [`LinkSettings::SEPARATOR`], as published by
+    /// [`LinkSettings::emit_metadata`] and stored in the link argument section of
+    /// [`link`](crate::link).
+    pub fn metadata(&self) -> String {
+        self.args().join(&Self::SEPARATOR.to_string())
+    }
+
+    /// Publishes the settings to the build scripts of dependent packages, for a package with a
+    /// `links` key. They read them with [`LinkSettings::from_dependency`].
+    pub fn emit_metadata(&self) {
+        println!("cargo:link_args={}", self.metadata());
+    }
+
+    /// Prints the `cargo:rustc-link-arg` lines for every target of the package.
+    pub fn emit(&self) {
+        self.emit_for(&LinkTarget::All);
+    }
+
+    /// Prints the `cargo:rustc-link-arg-*` lines for the given targets.
+    pub fn emit_for(&self, target: &LinkTarget) {
+        for line in self.lines(target) {
+            println!("{line}");
+        }
+    }
+
+    fn lines(&self, target: &LinkTarget) -> Vec<String> {
+        let prefix = match target {
+            LinkTarget::All => "cargo:rustc-link-arg=".to_string(),
+            LinkTarget::Bins => "cargo:rustc-link-arg-bins=".to_string(),
+            LinkTarget::Bin(name) => format!("cargo:rustc-link-arg-bin={name}="),
+            LinkTarget::Examples => "cargo:rustc-link-arg-examples=".to_string(),
+            LinkTarget::Tests => "cargo:rustc-link-arg-tests=".to_string(),
+            LinkTarget::Benches => "cargo:rustc-link-arg-benches=".to_string(),
+            LinkTarget::Cdylib => "cargo:rustc-link-arg-cdylib=".to_string(),
+        };
+        self.args()
+            .into_iter()
+            .map(|arg| format!("{prefix}{arg}"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        let settings = LinkSettings::new()
+            .allow_memory_growth(true)
+            .initial_memory(32 << 20)
+            .allow_memory_growth(false)
+            .exported_runtime_methods(["ccall"])
+            .exported_runtime_methods(["UTF8ToString", "ccall"])
+            .environment([Environment::Web, Environment::Worker])
+            .pthreads(true)
+            .pthread_pool_size_expr("navigator.hardwareConcurrency")
+            .audio_worklet(true);
+        assert_eq!(
+            settings.args(),
+            [
+                "-pthread",
+                "-sALLOW_MEMORY_GROWTH=0",
+                "-sINITIAL_MEMORY=33554432",
+                "-sEXPORTED_RUNTIME_METHODS=['ccall','UTF8ToString']",
+                "-sENVIRONMENT=web,worker",
+                "-sPTHREAD_POOL_SIZE=navigator.hardwareConcurrency",
+                "-sAUDIO_WORKLET=1",
+                "-sWASM_WORKERS=1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quoting() {
+        let settings = LinkSettings::new().asyncify_imports(["env.it's"]);
+        assert_eq!(settings.args(), [r"-sASYNCIFY_IMPORTS=['env.it\'s']"]);
+    }
+
+    #[test]
+    fn test_from_args() {
+        let settings = LinkSettings::new()
+            .pthreads(true)
+            .fetch(true)
+            .export_name("My App")
+            .exported_runtime_methods(["ccall", "it's"]);
+        let metadata = settings.metadata();
+        let parsed = LinkSettings::from_args(metadata.split(LinkSettings::SEPARATOR))
+            .exported_runtime_methods(["cwrap"]);
+        assert_eq!(
+            parsed.args(),
+            [
+                "-pthread",
+                "-sFETCH=1",
+                "-sEXPORT_NAME=My App",
+                r"-sEXPORTED_RUNTIME_METHODS=['ccall','it\'s','cwrap']",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let settings = LinkSettings::new().fetch(true);
+        assert_eq!(
+            settings.lines(&LinkTarget::Examples),
+            ["cargo:rustc-link-arg-examples=-sFETCH=1"]
+        );
+        assert_eq!(
+            settings.lines(&LinkTarget::Bin("app".to_string())),
+            ["cargo:rustc-link-arg-bin=app=-sFETCH=1"]
+        );
+    }
+}
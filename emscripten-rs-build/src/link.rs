@@ -0,0 +1,343 @@
+//! A linker wrapper around emcc, installed as the `emcc-rs` binary.
+//!
+//! Without inline assembly, `js!` functions of emscripten-rs-sys cannot be exported as `EM_JS`
+//! symbols. Their `--js-library` code is stored in the [`JS_SECTION`] custom section of the
+//! object files instead, and the wrapper collects it and passes it to emcc. It also passes the
+//! link settings stored in the [`LINK_ARGS_SECTION`] section, such as the ones of the cargo
+//! features of emscripten-rs-sys, which cargo does not forward to the final artifact itself.
+//! The wrapper is required on stable toolchains, and optional otherwise:
+//!
+//! ```toml
+//! # .cargo/config.toml
+//! [target.wasm32-unknown-emscripten]
+//! linker = "emcc-rs"
+//! ```
+//!
+//! The `EMCC` environment variable overrides the emcc command that is run.
+
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+
+/// The custom section holding the `addToLibrary` calls of `js!` functions.
+pub const JS_SECTION: &str = "emscripten_rs_js";
+
+/// The custom section holding emcc arguments, separated by [`LinkSettings::SEPARATOR`].
+///
+/// [`LinkSettings::SEPARATOR`]: crate::LinkSettings::SEPARATOR
+pub const LINK_ARGS_SECTION: &str = "emscripten_rs_link_args";
+
+/// Reads an unsigned LEB128 number, as used for the sizes in wasm binaries.
+fn read_leb(bytes: &[u8], pos: &mut usize) -> Option<usize> {
+    let mut value = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        value |= ((byte & 0x7f) as usize).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
+
+/// The contents of the custom sections called `name` in a wasm object file, empty if `bytes` is
+/// not one.
+fn custom_sections<'a>(bytes: &'a [u8], name: &str) -> Vec<&'a [u8]> {
+    let mut sections = Vec::new();
+    if !bytes.starts_with(b"\0asm") || bytes.len() < 8 {
+        return sections;
+    }
+    let mut pos = 8;
+    while pos < bytes.len() {
+        let id = bytes[pos];
+        pos += 1;
+        let Some(size) = read_leb(bytes, &mut pos) else {
+            break;
+        };
+        let Some(section) = bytes.get(pos..pos.saturating_add(size)) else {
+            break;
+        };
+        pos += size;
+        if id != 0 {
+            continue;
+        }
+        let mut name_pos = 0;
+        let Some(name_len) = read_leb(section, &mut name_pos) else {
+            continue;
+        };
+        if section.get(name_pos..name_pos.saturating_add(name_len)) == Some(name.as_bytes()) {
+            sections.push(&section[name_pos + name_len..]);
+        }
+    }
+    sections
+}
+
+/// The members of an `ar` archive such as an rlib, empty if `bytes` is not one.
+fn archive_members(bytes: &[u8]) -> Vec<&[u8]> {
+    const HEADER: usize = 60;
+
+    let mut members = Vec::new();
+    if !bytes.starts_with(b"!<arch>\n") {
+        return members;
+    }
+    let mut pos = 8;
+    while let Some(header) = bytes.get(pos..pos + HEADER) {
+        let field = |range: std::ops::Range<usize>| {
+            std::str::from_utf8(&header[range])
+                .unwrap_or_default()
+                .trim()
+        };
+        let Ok(size) = field(48..58).parse::<usize>() else {
+            break;
+        };
+        let start = pos + HEADER;
+        let Some(mut member) = bytes.get(start..start.saturating_add(size)) else {
+            break;
+        };
+        // BSD archives store long names at the start of the member.
+        if let Some(name_len) = field(0..16)
+            .strip_prefix("#1/")
+            .and_then(|len| len.parse::<usize>().ok())
+        {
+            member = member.get(name_len..).unwrap_or_default();
+        }
+        members.push(member);
+        pos = start + size + size % 2;
+    }
+    members
+}
+
+/// The contents of the custom sections called `name` in `bytes`, a wasm object file or an archive
+/// of them.
+fn sections<'a>(bytes: &'a [u8], name: &str) -> Vec<&'a [u8]> {
+    let members = archive_members(bytes);
+    let objects = if members.is_empty() {
+        vec![bytes]
+    } else {
+        members
+    };
+    objects
+        .into_iter()
+        .flat_map(|object| custom_sections(object, name))
+        .collect()
+}
+
+/// Adds the arguments stored in a link argument section to `args`, skipping known ones.
+fn add_stored_args(section: &[u8], args: &mut Vec<String>) {
+    for arg in String::from_utf8_lossy(section).split(crate::LinkSettings::SEPARATOR) {
+        if !arg.is_empty() && !args.iter().any(|known| known == arg) {
+            args.push(arg.to_string());
+        }
+    }
+}
+
+/// The arguments of a response file written by rustc, one per line with backslash escapes.
+fn response_file_args(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| {
+            let mut arg = String::new();
+            let mut chars = line.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => arg.extend(chars.next()),
+                    c => arg.push(c),
+                }
+            }
+            arg
+        })
+        .collect()
+}
+
+/// The input files among the linker arguments, and the output file.
+fn inputs(args: &[OsString]) -> (Vec<PathBuf>, Option<PathBuf>) {
+    let mut expanded = Vec::new();
+    for arg in args {
+        match arg.to_str().and_then(|arg| arg.strip_prefix('@')) {
+            Some(file) => match fs::read_to_string(file) {
+                Ok(text) => expanded.extend(response_file_args(&text).into_iter().map(Into::into)),
+                Err(_) => expanded.push(arg.clone()),
+            },
+            None => expanded.push(arg.clone()),
+        }
+    }
+
+    let mut files = Vec::new();
+    let mut output = None;
+    let mut args = expanded.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "-o" {
+            output = args.next().map(PathBuf::from);
+        } else if !arg.to_string_lossy().starts_with('-') {
+            let path = PathBuf::from(arg);
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    (files, output)
+}
+
+/// The arguments to add to the emcc command line: the stored link settings, and a JS library
+/// with the `js!` functions of the inputs, written next to the output file.
+pub fn emcc_args(args: &[OsString]) -> io::Result<Vec<OsString>> {
+    let (files, output) = inputs(args);
+    let mut code = String::new();
+    let mut link_args = Vec::new();
+    for file in &files {
+        let bytes = fs::read(file)?;
+        for section in sections(&bytes, JS_SECTION) {
+            code.push_str(&String::from_utf8_lossy(section));
+        }
+        for section in sections(&bytes, LINK_ARGS_SECTION) {
+            add_stored_args(section, &mut link_args);
+        }
+    }
+
+    let mut extra = link_args
+        .into_iter()
+        .map(OsString::from)
+        .collect::<Vec<_>>();
+    if !code.is_empty() {
+        let path = match output {
+            Some(output) => output.with_extension("rs-library.js"),
+            None => std::env::temp_dir().join(format!("emcc-rs-{}.js", std::process::id())),
+        };
+        // Leave an unchanged library alone, so that its timestamp stays the same.
+        if fs::read_to_string(&path).ok().as_deref() != Some(code.as_str()) {
+            fs::write(&path, code)?;
+        }
+        extra.push("--js-library".into());
+        extra.push(path.into());
+    }
+    Ok(extra)
+}
+
+fn emcc() -> OsString {
+    std::env::var_os("EMCC").unwrap_or_else(|| {
+        if cfg!(windows) {
+            "emcc.bat".into()
+        } else {
+            "emcc".into()
+        }
+    })
+}
+
+fn run(args: Vec<OsString>) -> io::Result<ExitCode> {
+    let status = Command::new(emcc()).args(args).status()?;
+    Ok(match status.code() {
+        Some(code) => ExitCode::from(code as u8),
+        None => ExitCode::FAILURE,
+    })
+}
+
+/// Runs emcc with the arguments of the process, adding the link settings and the JS library of
+/// the `js!` functions found in the inputs.
+pub fn main() -> ExitCode {
+    let mut args = std::env::args_os().skip(1).collect::<Vec<_>>();
+    match emcc_args(&args) {
+        Ok(extra) => args.extend(extra),
+        Err(error) => {
+            eprintln!("emcc-rs: could not read the linker inputs: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    match run(args) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("emcc-rs: could not run emcc: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A wasm object with a type section and the given custom sections.
+    fn object(sections: &[(&str, &str)]) -> Vec<u8> {
+        let mut bytes = b"\0asm\x01\0\0\0".to_vec();
+        bytes.extend([1, 1, 0]);
+        for (name, contents) in sections {
+            let size = 1 + name.len() + contents.len();
+            bytes.push(0);
+            bytes.extend([(size as u8 & 0x7f) | 0x80, (size >> 7) as u8]);
+            bytes.push(name.len() as u8);
+            bytes.extend(name.as_bytes());
+            bytes.extend(contents.as_bytes());
+        }
+        bytes
+    }
+
+    fn archive(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut bytes = b"!<arch>\n".to_vec();
+        for (name, member) in members {
+            bytes.extend(format!("{name:<16}{:<32}{:<10}`\n", "0", member.len()).as_bytes());
+            bytes.extend(*member);
+            if member.len() % 2 == 1 {
+                bytes.push(b'\n');
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_read_leb() {
+        let mut pos = 0;
+        assert_eq!(read_leb(&[0xe5, 0x8e, 0x26, 1], &mut pos), Some(624485));
+        assert_eq!(pos, 3);
+        assert_eq!(read_leb(&[0x80], &mut 0), None);
+    }
+
+    #[test]
+    fn test_custom_sections() {
+        let object = object(&[
+            (JS_SECTION, "addToLibrary({ a: () => 1 });\n"),
+            ("producers", "rustc"),
+            (JS_SECTION, "addToLibrary({ b: () => 2 });\n"),
+        ]);
+        assert_eq!(
+            custom_sections(&object, JS_SECTION),
+            [
+                b"addToLibrary({ a: () => 1 });\n".as_slice(),
+                b"addToLibrary({ b: () => 2 });\n".as_slice(),
+            ]
+        );
+        assert!(custom_sections(b"not wasm", JS_SECTION).is_empty());
+        assert!(custom_sections(&object[..20], JS_SECTION).is_empty());
+    }
+
+    #[test]
+    fn test_sections() {
+        let first = object(&[(JS_SECTION, "a;")]);
+        let second = object(&[(JS_SECTION, "b;")]);
+        let rlib = archive(&[
+            ("lib.rmeta/", b"rust metadata"),
+            ("#1/8", &[b"first.o\0".as_slice(), &first].concat()),
+            ("second.o/", &second),
+        ]);
+        assert_eq!(sections(&rlib, JS_SECTION), [b"a;", b"b;"]);
+        assert_eq!(sections(&second, JS_SECTION), [b"b;"]);
+    }
+
+    #[test]
+    fn test_add_stored_args() {
+        let settings = crate::LinkSettings::new().pthreads(true).fetch(true);
+        let mut args = Vec::new();
+        add_stored_args(settings.metadata().as_bytes(), &mut args);
+        add_stored_args(b"-sFETCH=1\x1f-sEXPORT_NAME=My App", &mut args);
+        assert_eq!(args, ["-pthread", "-sFETCH=1", "-sEXPORT_NAME=My App"]);
+    }
+
+    #[test]
+    fn test_response_file_args() {
+        assert_eq!(
+            response_file_args("-o\nout dir\\\\app.js\n--js-library\\ x\n"),
+            ["-o", "out dir\\app.js", "--js-library x"]
+        );
+    }
+}
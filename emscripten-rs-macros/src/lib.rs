@@ -1,6 +1,16 @@
+use std::collections::BTreeMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+
 use proc_macro::TokenStream;
-use quote::{ToTokens, quote};
-use syn::*;
+use proc_macro2::Literal;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, *};
+
+mod library;
+mod signature;
+#[cfg(feature = "js_syntax_check")]
+mod validate;
 
 fn trim_script(script: String) -> String {
     script
@@ -13,21 +23,63 @@ fn trim_script(script: String) -> String {
         .collect::<String>()
 }
 
-#[proc_macro]
-pub fn len_in_bytes(input: TokenStream) -> TokenStream {
-    let params = parse_macro_input!(input as ExprTuple);
+/// A suffix for the symbols of a snippet, made of a hash of the crate and the code, and a counter
+/// of the expansions in the crate. The location of the call site alone is not unique, since a
+/// `macro_rules!` macro can expand several snippets at the same one, and the counter only depends
+/// on the order of the expansions within the crate, as the macro server may be shared by crates.
+fn expansion_id(code: &str) -> String {
+    static EXPANSIONS: Mutex<BTreeMap<(String, String), usize>> = Mutex::new(BTreeMap::new());
+    let krate = (
+        std::env::var("CARGO_CRATE_NAME").unwrap_or_default(),
+        std::env::var("CARGO_PKG_VERSION").unwrap_or_default(),
+    );
+    let mut hasher = DefaultHasher::new();
+    (&krate, code).hash(&mut hasher);
+    let mut expansions = EXPANSIONS.lock().unwrap_or_else(|error| error.into_inner());
+    let counter = expansions.entry(krate).or_default();
+    *counter += 1;
+    format!("{:016x}_{}", hasher.finish(), *counter - 1)
+}
 
-    let args = params.elems[0].to_token_stream().to_string();
-    let stringify = parse2::<ExprMacro>(params.elems[1].to_token_stream()).unwrap();
-    let script = trim_script(stringify.mac.tokens.to_string());
+struct ScriptInput {
+    sig: signature::Signature,
+    #[cfg_attr(not(feature = "js_syntax_check"), allow(dead_code))]
+    body: proc_macro2::TokenStream,
+    script: String,
+}
 
-    let mut len = script.len() + 1;
+impl parse::Parse for ScriptInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let sig = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let stringify = content.parse::<ExprMacro>()?;
+        let body = stringify.mac.tokens;
+        let script = trim_script(body.to_string());
+        Ok(Self { sig, body, script })
+    }
+}
+
+impl ScriptInput {
+    fn decorated(&self) -> String {
+        let args = signature::js_params(&self.sig).join(", ");
+        let open = signature::js_open(&self.sig);
+        let close = signature::js_close(&self.sig);
+        if open.is_empty() && close.is_empty() {
+            format!("({args})<::>{}", self.script)
+        } else {
+            format!("({args})<::>{{ {open}{}{close}}}", self.script)
+        }
+    }
+}
 
-    // Additional bytes from decoration
-    len += 4;
+#[proc_macro]
+pub fn len_in_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ScriptInput);
 
-    // Arguments length
-    len += args.len();
+    // Including the terminating null byte
+    let len = input.decorated().len() + 1;
 
     // Produce a compile-time usize literal
     let expanded = quote! { #len };
@@ -36,18 +88,27 @@ pub fn len_in_bytes(input: TokenStream) -> TokenStream {
 
 #[proc_macro]
 pub fn get_decorated_script(input: TokenStream) -> TokenStream {
-    let params = parse_macro_input!(input as ExprTuple);
+    let input = parse_macro_input!(input as ScriptInput);
 
-    let args = params.elems[0].to_token_stream().to_string();
-    let stringify = parse2::<ExprMacro>(params.elems[1].to_token_stream()).unwrap();
-    let script = trim_script(stringify.mac.tokens.to_string());
-
-    let decorated_script = format!("{args}<::>{script}");
+    let decorated_script = input.decorated();
     let bytes = decorated_script.as_bytes();
 
     // Turn each byte into a literal token
     let tokens = bytes.iter().map(|b| quote! { #b });
 
+    #[cfg(feature = "js_syntax_check")]
+    if let Some(errors) = validate::check(
+        &signature::js_params(&input.sig),
+        input.sig.awaits(),
+        input.body.clone(),
+    ) {
+        let errors = errors.to_compile_error();
+        return quote! {
+            { #errors [ #( #tokens ),*, b'\0' ] }
+        }
+        .into();
+    }
+
     let output = quote! {
         [ #( #tokens ),*, b'\0' ]
     };
@@ -55,6 +116,451 @@ pub fn get_decorated_script(input: TokenStream) -> TokenStream {
     output.into()
 }
 
+struct SourceInput {
+    sig: signature::Signature,
+    #[cfg_attr(not(feature = "js_syntax_check"), allow(dead_code))]
+    source: Expr,
+}
+
+impl parse::Parse for SourceInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let sig = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let source = content.parse()?;
+        Ok(Self { sig, source })
+    }
+}
+
+/// The decoration placed before JS source text given as a string, up to and including the opening
+/// brace of the function body.
+#[proc_macro]
+pub fn script_prefix(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as SourceInput);
+
+    #[cfg(feature = "js_syntax_check")]
+    {
+        let mut source = &input.source;
+        while let Expr::Group(group) = source {
+            source = &group.expr;
+        }
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(text),
+            ..
+        }) = source
+            && let Some(error) = validate::check_text(
+                &signature::js_params(&input.sig),
+                input.sig.awaits(),
+                &text.value(),
+            )
+        {
+            return Error::new(text.span(), error).to_compile_error().into();
+        }
+    }
+
+    let args = signature::js_params(&input.sig).join(", ");
+    let open = signature::js_open(&input.sig);
+    let prefix = format!("({args})<::>{{ {open}\n");
+
+    quote! { #prefix }.into()
+}
+
+/// The decoration placed after JS source text given as a string, closing the function body.
+#[proc_macro]
+pub fn script_suffix(input: TokenStream) -> TokenStream {
+    let sig = parse_macro_input!(input as signature::Signature);
+    let suffix = format!("\n{}}}", signature::js_close(&sig));
+
+    quote! { #suffix }.into()
+}
+
+struct LibDepsInput {
+    export: Ident,
+    name: Ident,
+    sig: signature::Signature,
+}
+
+impl parse::Parse for LibDepsInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let export = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let sig = input.parse()?;
+        Ok(Self { export, name, sig })
+    }
+}
+
+/// Exports the `__em_lib_deps_` symbol listing the JS library functions a `js!` function relies on,
+/// the equivalent of `EM_JS_DEPS`.
+#[proc_macro]
+pub fn export_lib_deps(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as LibDepsInput);
+    let deps = signature::js_deps(&input.sig);
+    if deps.is_empty() {
+        return TokenStream::new();
+    }
+
+    let export = &input.export;
+    let symbol = format_ident!("__em_lib_deps_{}", input.name);
+    let mut bytes = deps.join(",").into_bytes();
+    bytes.push(0);
+    let len = bytes.len();
+    let bytes = Literal::byte_string(&bytes);
+
+    let expanded = quote! {
+        ::emscripten_rs_sys::export_bytes!(#export, #symbol, *#bytes, #len);
+    };
+    expanded.into()
+}
+
+struct ImportInput {
+    name: Ident,
+    sig: signature::Signature,
+}
+
+impl parse::Parse for ImportInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let sig = input.parse()?;
+        Ok(Self { name, sig })
+    }
+}
+
+/// Declares the Rust side of a JS function defined with `js!`.
+#[proc_macro]
+pub fn js_import(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ImportInput);
+    signature::declare(&input.name, &input.sig)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct SymbolInput {
+    export: Ident,
+    prefix: Ident,
+    name: Ident,
+    sig: Option<signature::Signature>,
+    code: Expr,
+    size: Expr,
+}
+
+impl parse::Parse for SymbolInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let export = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let prefix = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let name = input.parse()?;
+        let sig = if input.peek(token::Bracket) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        input.parse::<Token![,]>()?;
+        let code = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let size = input.parse()?;
+        Ok(Self {
+            export,
+            prefix,
+            name,
+            sig,
+            code,
+            size,
+        })
+    }
+}
+
+/// Exports `code` under the symbol `prefix` followed by `name`, e.g. `__em_js__foo`. When the
+/// signature follows the name, the JS name of the function is used instead.
+#[proc_macro]
+pub fn export_symbol(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as SymbolInput);
+    let export = &input.export;
+    let name = match &input.sig {
+        Some(sig) => signature::js_name(&input.name, sig),
+        None => input.name.to_string(),
+    };
+    let symbol = format_ident!("{}{}", input.prefix, name);
+    let code = &input.code;
+    let size = &input.size;
+
+    let expanded = quote! {
+        ::emscripten_rs_sys::export_bytes!(#export, #symbol, #code, #size);
+    };
+    expanded.into()
+}
+
+enum LibraryBody {
+    Tokens(proc_macro2::TokenStream),
+    Source(Box<Expr>),
+}
+
+struct LibraryInput {
+    name: Ident,
+    sig: signature::Signature,
+    body: LibraryBody,
+}
+
+impl parse::Parse for LibraryInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let sig = input.parse()?;
+        let body = if input.parse::<Option<Token![=]>>()?.is_some() {
+            LibraryBody::Source(input.parse()?)
+        } else {
+            input.parse::<Token![,]>()?;
+            let content;
+            braced!(content in input);
+            LibraryBody::Tokens(content.parse()?)
+        };
+        Ok(Self { name, sig, body })
+    }
+}
+
+/// Defines a `js!` function with `--js-library` code instead of an `__em_js__` symbol, for
+/// toolchains that cannot export symbols with inline assembly. The code is stored in the
+/// `emscripten_rs_js` custom section, which the `emcc-rs` linker of emscripten-rs-build passes to
+/// emcc.
+#[proc_macro]
+pub fn js_library(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as LibraryInput);
+    let name = &input.name;
+
+    let (body, tracked) = match &input.body {
+        LibraryBody::Tokens(tokens) => {
+            #[cfg(feature = "js_syntax_check")]
+            if let Some(errors) = validate::check(
+                &signature::js_params(&input.sig),
+                input.sig.awaits(),
+                tokens.clone(),
+            ) {
+                return errors.to_compile_error().into();
+            }
+            (format!("{{ {}}}", trim_script(tokens.to_string())), None)
+        }
+        LibraryBody::Source(source) => {
+            let text = match library::source_text(source) {
+                Ok(text) => text,
+                Err(error) => return error.to_compile_error().into(),
+            };
+            #[cfg(feature = "js_syntax_check")]
+            if let Some(error) =
+                validate::check_text(&signature::js_params(&input.sig), input.sig.awaits(), &text)
+            {
+                return Error::new_spanned(source, error).to_compile_error().into();
+            }
+            // Evaluating the source keeps included files tracked for recompilation.
+            (text, Some(quote! { const _: &str = #source; }))
+        }
+    };
+
+    let contents = library::contents(name, &input.sig, &body);
+    let len = contents.len();
+    let contents = Literal::byte_string(contents.as_bytes());
+    let import = match signature::declare(name, &input.sig) {
+        Ok(import) => import,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    // Custom sections of the same name are concatenated, so each function only needs an anonymous
+    // static.
+    let expanded = quote! {
+        #tracked
+
+        const _: () = {
+            #[used]
+            #[unsafe(link_section = "emscripten_rs_js")]
+            static LIBRARY: [u8; #len] = *#contents;
+        };
+
+        #import
+    };
+    expanded.into()
+}
+
+struct AsmInput {
+    mode: Option<Ident>,
+    ret: Option<Type>,
+    code: LitStr,
+    args: Vec<Expr>,
+}
+
+impl parse::Parse for AsmInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let mode = if input.peek(Ident) {
+            let mode = input.parse::<Ident>()?;
+            if mode != "sync_on_main_thread" && mode != "async_on_main_thread" {
+                return Err(Error::new(
+                    mode.span(),
+                    "expected `sync_on_main_thread` or `async_on_main_thread`",
+                ));
+            }
+            Some(mode)
+        } else {
+            None
+        };
+        let ret = if input.parse::<Option<Token![->]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        if let (Some(mode), Some(ret)) = (&mode, &ret)
+            && mode == "async_on_main_thread"
+        {
+            return Err(Error::new_spanned(
+                ret,
+                "`async_on_main_thread` snippets cannot return a value",
+            ));
+        }
+        if mode.is_some() || ret.is_some() {
+            input.parse::<Token![,]>()?;
+        }
+        let code = input.parse()?;
+        let mut args = Vec::new();
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            args.push(input.parse()?);
+        }
+        Ok(Self {
+            mode,
+            ret,
+            code,
+            args,
+        })
+    }
+}
+
+/// Gives unsuffixed numeric literals the types C would, since the argument type has to be known
+/// before the variadic call.
+fn asm_arg(arg: &Expr) -> proc_macro2::TokenStream {
+    let mut literal = arg;
+    if let Expr::Unary(ExprUnary {
+        op: UnOp::Neg(_),
+        expr,
+        ..
+    }) = literal
+    {
+        literal = expr;
+    }
+    match literal {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) if int.suffix().is_empty() => quote! { ::std::convert::identity::<i32>(#arg) },
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(float),
+            ..
+        }) if float.suffix().is_empty() => quote! { ::std::convert::identity::<f64>(#arg) },
+        _ => quote! { #arg },
+    }
+}
+
+/// Runs a JS snippet stored in the `em_asm` section through `emscripten_asm_const_*`.
+#[proc_macro]
+pub fn em_asm(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AsmInput);
+    let code = input.code.value();
+
+    #[cfg(feature = "js_syntax_check")]
+    {
+        let params = (0..input.args.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>();
+        if let Some(error) = validate::check_text(&params, false, &code) {
+            return Error::new(input.code.span(), error)
+                .to_compile_error()
+                .into();
+        }
+    }
+
+    // The symbol only has to be unique within the final binary.
+    let call_site = proc_macro2::Span::call_site().unwrap();
+    let mut location = format!(
+        "{}_{}_{}_{}",
+        std::env::var("CARGO_CRATE_NAME").unwrap_or_default(),
+        call_site.file(),
+        call_site.line(),
+        call_site.column()
+    );
+    location.retain(|c| c.is_ascii_alphanumeric() || c == '_');
+    let symbol = format!("__em_asm_rs_{location}_{}", expansion_id(&code));
+
+    let mut bytes = code.into_bytes();
+    bytes.push(0);
+    let data = bytes
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    let section = [
+        ".section em_asm,\"\",@".to_string(),
+        format!(".globl {symbol}"),
+        format!(".type {symbol},@object"),
+        format!("{symbol}:"),
+        format!(".byte {data}"),
+        format!(".size {symbol}, {}", bytes.len()),
+    ];
+
+    let names = (0..input.args.len())
+        .map(|i| format_ident!("__arg{}", i))
+        .collect::<Vec<_>>();
+    let args = input.args.iter().map(asm_arg);
+    let ret = match &input.ret {
+        Some(ret) => quote! { #ret },
+        None => quote! { () },
+    };
+    let suffix = match &input.mode {
+        Some(mode) if mode == "sync_on_main_thread" => "_sync_on_main_thread",
+        _ => "",
+    };
+    let [int, double, ptr] = ["int", "double", "ptr"]
+        .map(|variant| format_ident!("emscripten_asm_const_{}{}", variant, suffix));
+
+    let call = match &input.mode {
+        Some(mode) if mode == "async_on_main_thread" => quote! {
+            ::emscripten_rs_sys::emscripten_asm_const_async_on_main_thread(code, sig, #( #names ),*)
+        },
+        _ => quote! {
+            match <#ret as AsmRet>::RETURN {
+                AsmReturn::Int => <#ret as AsmRet>::from_int(
+                    ::emscripten_rs_sys::#int(code, sig, #( #names ),*)
+                ),
+                AsmReturn::Double => <#ret as AsmRet>::from_double(
+                    ::emscripten_rs_sys::#double(code, sig, #( #names ),*)
+                ),
+                AsmReturn::Ptr => <#ret as AsmRet>::from_ptr(
+                    ::emscripten_rs_sys::#ptr(code, sig, #( #names ),*)
+                ),
+            }
+        },
+    };
+
+    let expanded = quote! {
+        {
+            mod code {
+                ::std::arch::global_asm!(#( #section ),*);
+            }
+
+            unsafe extern "C" {
+                #[link_name = #symbol]
+                static CODE: u8;
+            }
+
+            #[allow(unused_imports)]
+            use ::emscripten_rs_sys::asm::{AsmRet, AsmReturn};
+
+            #( let #names = ::emscripten_rs_sys::asm::AsmArg::into_abi(#args); )*
+            let code = &raw const CODE as *const ::std::ffi::c_char;
+            let sig = ::emscripten_rs_sys::asm::signature(&(#( #names, )*));
+            unsafe { #call }
+        }
+    };
+    expanded.into()
+}
+
 /// Generates a unique macro for each call site.
 /// This is an ugly workaround for the problem that normal
 /// macros don't accept the result of proc macros.
@@ -67,7 +573,14 @@ pub fn inline_js(input: TokenStream) -> TokenStream {
     let mut file = loc.file();
     file.retain(|c| c.is_ascii_alphanumeric());
     let name = syn::Ident::new(
-        &format!("_em_asm_{}_{}_{}", file, loc.line(), loc.column()),
+        &format!(
+            "_em_asm_{}_{}_{}_{}_{}",
+            std::env::var("CARGO_CRATE_NAME").unwrap_or_default(),
+            file,
+            loc.line(),
+            loc.column(),
+            expansion_id(&tokens.to_string())
+        ),
         call_site,
     );
 
@@ -78,34 +591,18 @@ pub fn inline_js(input: TokenStream) -> TokenStream {
                     ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)?, $($body:tt)*
                 ) => {
                     {
-                        unsafe {
-                            $crate::export_script_to_linker!(asm, #name, $($arg_name)*, $($body)*);
-                        }
-                
-                        #[link(wasm_import_module = "env")]
-                        #[allow(dead_code)]
-                        unsafe extern "C" {
-                            pub unsafe fn #name($( $arg_name : $arg_ty ),*) $(-> $ret)?;
-                        }
-    
+                        ::emscripten_rs_sys::js_fn!(asm, #name, [($($arg_name : $arg_ty),*) $(-> $ret)?], { $($body)* });
+
                         unsafe { #name($($arg_name),*) }
                     }
                 };
-    
+
                 (
                     $($body:tt)*
                 ) => {
                     {
-                        unsafe {
-                            $crate::export_script_to_linker!(asm, #name,  , $($body)*);
-                        }
-                
-                        #[link(wasm_import_module = "env")]
-                        #[allow(dead_code)]
-                        unsafe extern "C" {
-                            pub unsafe fn #name();
-                        }
-    
+                        ::emscripten_rs_sys::js_fn!(asm, #name, [()], { $($body)* });
+
                         unsafe { #name() }
                     }
                 };
@@ -115,4 +612,106 @@ pub fn inline_js(input: TokenStream) -> TokenStream {
         }
     };
     expanded.into()
-}
\ No newline at end of file
+}
+
+fn named_fields(input: &DeriveInput) -> Result<Vec<&Ident>> {
+    match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => Ok(fields
+            .named
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap())
+            .collect()),
+        _ => Err(Error::new_spanned(
+            input,
+            "only structs with named fields can be converted to and from JS objects",
+        )),
+    }
+}
+
+/// The generics of `input` with `bound` on every type parameter.
+fn bounded_generics(input: &DeriveInput, bound: proc_macro2::TokenStream) -> Generics {
+    let mut generics = input.generics.clone();
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote! { #param: #bound });
+    }
+    generics
+}
+
+/// The JS property names of `fields`, without the `r#` of raw identifiers.
+fn js_keys(fields: &[&Ident]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| field.unraw().to_string())
+        .collect()
+}
+
+/// Converts a struct into a plain JS object with one property per field.
+#[proc_macro_derive(ToJs)]
+pub fn derive_to_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let keys = js_keys(&fields);
+    let name = &input.ident;
+    let generics = bounded_generics(&input, quote! { ::emscripten_rs_sys::emval::ToJs });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::emscripten_rs_sys::emval::ToJs for #name #ty_generics #where_clause {
+            fn to_js(&self) -> ::emscripten_rs_sys::emval::Val {
+                let object = ::emscripten_rs_sys::emval::Val::object();
+                #(
+                    object.set(#keys, &::emscripten_rs_sys::emval::ToJs::to_js(&self.#fields));
+                )*
+                object
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Reads a struct from a JS object with one property per field.
+#[proc_macro_derive(FromJs)]
+pub fn derive_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let keys = js_keys(&fields);
+    let name = &input.ident;
+    let generics = bounded_generics(&input, quote! { ::emscripten_rs_sys::emval::FromJs });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::emscripten_rs_sys::emval::FromJs for #name #ty_generics #where_clause {
+            fn from_js(
+                val: &::emscripten_rs_sys::emval::Val,
+            ) -> ::std::result::Result<Self, ::emscripten_rs_sys::emval::FromJsError> {
+                if val.is_null() || val.is_undefined() {
+                    return ::std::result::Result::Err(
+                        ::emscripten_rs_sys::emval::FromJsError::new("object"),
+                    );
+                }
+                ::std::result::Result::Ok(Self {
+                    #(
+                        #fields: ::emscripten_rs_sys::emval::FromJs::from_js(&val.get(#keys))?,
+                    )*
+                })
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::PathBuf;
+
+use syn::*;
+
+use crate::signature::{self, Signature};
+
+/// The contents of the `--js-library` file defining the function `name`.
+pub fn contents(name: &Ident, sig: &Signature, body: &str) -> String {
+    let js_name = signature::js_name(name, sig);
+    let args = signature::js_params(sig).join(", ");
+    let open = signature::js_open(sig);
+    let close = signature::js_close(sig);
+    let deps = signature::js_deps(sig)
+        .iter()
+        .map(|dep| format!("'{dep}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    // Marking asyncify imports as `__async` adds them to `ASYNCIFY_IMPORTS`.
+    let asyncify = match sig.asyncify {
+        Some(_) => format!("  {js_name}__async: true,\n"),
+        None => String::new(),
+    };
+    // Library functions are defined as `_name`, the postset makes them callable by the same name
+    // as `EM_JS` functions from other `js!` bodies.
+    format!(
+        "addToLibrary({{\n  {js_name}: function({args}) {{ {open}\n{body}\n{close}}},\n  {js_name}__deps: [{deps}],\n  {js_name}__postset: 'var {js_name} = _{js_name};',\n{asyncify}}});\n"
+    )
+}
+
+/// The text of JS source given as a string literal or with `include_js!`/`include_str!`, which is
+/// all a proc macro can read without evaluating the expression.
+pub fn source_text(source: &Expr) -> Result<String> {
+    let mut expr = source;
+    while let Expr::Group(group) = expr {
+        expr = &group.expr;
+    }
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(text),
+            ..
+        }) => Ok(text.value()),
+        Expr::Macro(ExprMacro { mac, .. })
+            if mac.path.segments.last().is_some_and(|segment| {
+                segment.ident == "include_js" || segment.ident == "include_str"
+            }) =>
+        {
+            let path = mac.parse_body::<LitStr>()?;
+            // Relative paths are resolved like `include_str!` does, from the invoking file.
+            let file = path
+                .span()
+                .unwrap()
+                .local_file()
+                .and_then(|file| file.parent().map(|dir| dir.join(path.value())))
+                .unwrap_or_else(|| PathBuf::from(path.value()));
+            fs::read_to_string(&file).map_err(|error| {
+                Error::new(
+                    path.span(),
+                    format!("failed to read {}: {error}", file.display()),
+                )
+            })
+        }
+        _ => Err(Error::new_spanned(
+            source,
+            "without asm_experimental_arch, JS source text must be a string literal or include_js!",
+        )),
+    }
+}
@@ -0,0 +1,817 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::*;
+
+/// A `name: Type` parameter of a JS function.
+pub struct Param {
+    pub name: Ident,
+    pub ty: Type,
+}
+
+impl Parse for Param {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { name, ty })
+    }
+}
+
+/// A parenthesized, comma separated list of parameters.
+pub fn parse_params(input: ParseStream) -> Result<Vec<Param>> {
+    let content;
+    parenthesized!(content in input);
+    let params = Punctuated::<Param, Token![,]>::parse_terminated(&content)?;
+    Ok(params.into_iter().collect())
+}
+
+/// The signature of a `js!` function, passed between the macros in brackets as
+/// `[#[attr] async (name: Type, ...) -> Type]`.
+pub struct Signature {
+    pub proxy: Option<Proxy>,
+    pub asyncify: Option<Asyncify>,
+    pub asyncness: bool,
+    pub params: Vec<Param>,
+    pub ret: ReturnType,
+}
+
+impl Parse for Signature {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        bracketed!(content in input);
+        let (proxy, asyncify) = parse_attrs(&content.call(Attribute::parse_outer)?)?;
+        let asyncness = content.parse::<Option<Token![async]>>()?.is_some();
+        let params = parse_params(&content)?;
+        let ret = content.parse()?;
+        Ok(Self {
+            proxy,
+            asyncify,
+            asyncness,
+            params,
+            ret,
+        })
+    }
+}
+
+impl Signature {
+    /// Whether the body runs as a JS async function, where `await` is allowed.
+    #[cfg_attr(not(feature = "js_syntax_check"), allow(dead_code))]
+    pub fn awaits(&self) -> bool {
+        self.asyncness || self.asyncify == Some(Asyncify::Async)
+    }
+}
+
+/// The name of the JS function. Asyncify imports get the `__asyncjs__` prefix, which emcc adds to
+/// `ASYNCIFY_IMPORTS` by default, like `EM_ASYNC_JS` does.
+pub fn js_name(name: &Ident, sig: &Signature) -> String {
+    match sig.asyncify {
+        Some(_) => format!("__asyncjs__{name}"),
+        None => name.to_string(),
+    }
+}
+
+/// How a Rust parameter crosses the wasm boundary.
+enum ParamKind<'a> {
+    /// Passed through unchanged.
+    Scalar,
+    /// Passed as a pointer and a length, and rebuilt as a `TypedArray` view in JS.
+    Slice { array: &'static str, mutable: bool },
+    /// Boxed and passed as a data pointer plus call and drop trampolines from the function table,
+    /// and rebuilt as a callable JS function.
+    Closure {
+        signature: &'a ParenthesizedGenericArguments,
+        /// The bounds of an `impl FnMut(..)` parameter, which becomes a generic of the wrapper.
+        bounds: Option<&'a Punctuated<TypeParamBound, Token![+]>>,
+    },
+}
+
+/// Whether `ty` is a 64-bit integer, which crosses the wasm boundary as a JS BigInt.
+fn is_bigint(ty: &Type) -> bool {
+    matches!(strip_groups(ty), Type::Path(path)
+    if path.path.segments.last().is_some_and(|segment| {
+        ["i64", "u64", "c_longlong", "c_ulonglong"].contains(&segment.ident.to_string().as_str())
+    }))
+}
+
+/// Whether `bounds` include `Send`.
+fn has_send(bounds: &Punctuated<TypeParamBound, Token![+]>) -> bool {
+    bounds.iter().any(|bound| {
+        matches!(bound, TypeParamBound::Trait(bound)
+            if bound.path.segments.last().is_some_and(|segment| segment.ident == "Send"))
+    })
+}
+
+/// Whether the trait object of a `Box<dyn FnMut(..)>` parameter is `Send`.
+fn boxed_closure_is_send(ty: &Type) -> bool {
+    if let Type::Path(path) = strip_groups(ty)
+        && let Some(segment) = path.path.segments.last()
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(boxed)) = args.args.first()
+        && let Type::TraitObject(object) = strip_groups(boxed)
+    {
+        return has_send(&object.bounds);
+    }
+    false
+}
+
+fn strip_groups(ty: &Type) -> &Type {
+    match ty {
+        Type::Group(group) => strip_groups(&group.elem),
+        Type::Paren(paren) => strip_groups(&paren.elem),
+        ty => ty,
+    }
+}
+
+fn typed_array(elem: &Type) -> Option<&'static str> {
+    let Type::Path(path) = strip_groups(elem) else {
+        return None;
+    };
+    let ident = path.path.get_ident()?.to_string();
+    Some(match ident.as_str() {
+        "i8" => "Int8Array",
+        "u8" => "Uint8Array",
+        "i16" => "Int16Array",
+        "u16" => "Uint16Array",
+        "i32" => "Int32Array",
+        "u32" => "Uint32Array",
+        "i64" => "BigInt64Array",
+        "u64" => "BigUint64Array",
+        "f32" => "Float32Array",
+        "f64" => "Float64Array",
+        _ => return None,
+    })
+}
+
+/// Finds the `Fn(..) -> ..` or `FnMut(..) -> ..` bound in a list of trait bounds.
+fn closure_signature<'a>(
+    bounds: impl IntoIterator<Item = &'a TypeParamBound>,
+) -> Option<&'a ParenthesizedGenericArguments> {
+    bounds.into_iter().find_map(|bound| {
+        let TypeParamBound::Trait(bound) = bound else {
+            return None;
+        };
+        let segment = bound.path.segments.last()?;
+        match &segment.arguments {
+            PathArguments::Parenthesized(signature)
+                if segment.ident == "Fn" || segment.ident == "FnMut" =>
+            {
+                Some(signature)
+            }
+            _ => None,
+        }
+    })
+}
+
+fn classify(ty: &Type) -> ParamKind<'_> {
+    match strip_groups(ty) {
+        Type::Reference(reference) => {
+            if let Type::Slice(slice) = strip_groups(&reference.elem)
+                && let Some(array) = typed_array(&slice.elem)
+            {
+                return ParamKind::Slice {
+                    array,
+                    mutable: reference.mutability.is_some(),
+                };
+            }
+        }
+        Type::ImplTrait(impl_trait) => {
+            if let Some(signature) = closure_signature(&impl_trait.bounds) {
+                return ParamKind::Closure {
+                    signature,
+                    bounds: Some(&impl_trait.bounds),
+                };
+            }
+        }
+        Type::Path(path) => {
+            if let Some(segment) = path.path.segments.last()
+                && segment.ident == "Box"
+                && let PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(GenericArgument::Type(boxed)) = args.args.first()
+                && let Type::TraitObject(object) = strip_groups(boxed)
+                && let Some(signature) = closure_signature(&object.bounds)
+            {
+                return ParamKind::Closure {
+                    signature,
+                    bounds: None,
+                };
+            }
+        }
+        _ => {}
+    }
+    ParamKind::Scalar
+}
+
+/// The `T` of a `Result<T, JsError>` return type. The JS body of such functions runs in a
+/// try/catch that reports exceptions through an extra `__error` parameter.
+pub fn result_ok(ret: &ReturnType) -> Option<&Type> {
+    let ReturnType::Type(_, ty) = ret else {
+        return None;
+    };
+    let Type::Path(path) = strip_groups(ty) else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match (
+        segment.ident == "Result",
+        args.args.first(),
+        args.args.get(1),
+    ) {
+        (true, Some(GenericArgument::Type(ok)), Some(GenericArgument::Type(err)))
+            if matches!(strip_groups(err), Type::Path(err)
+                if err.path.segments.last().is_some_and(|segment| segment.ident == "JsError")) =>
+        {
+            Some(ok)
+        }
+        _ => None,
+    }
+}
+
+/// The parameter list of the JS function, after ABI lowering.
+pub fn js_params(sig: &Signature) -> Vec<String> {
+    let mut names = sig
+        .params
+        .iter()
+        .flat_map(|param| {
+            let name = param.name.to_string();
+            match classify(&param.ty) {
+                ParamKind::Scalar => vec![name],
+                ParamKind::Slice { .. } => vec![name.clone(), format!("{name}$len")],
+                ParamKind::Closure { .. } => {
+                    vec![name.clone(), format!("{name}$call"), format!("{name}$drop")]
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if sig.asyncness {
+        names.extend(["__state", "__resolve", "__reject"].map(String::from));
+    } else if result_ok(&sig.ret).is_some() {
+        names.push("__error".to_string());
+    }
+    names
+}
+
+/// JS statements that turn the lowered parameters back into their JS representation.
+fn js_prologue(sig: &Signature) -> String {
+    sig.params
+        .iter()
+        .map(|param| {
+            let name = &param.name;
+            match classify(&param.ty) {
+                ParamKind::Scalar => String::new(),
+                // Views are created from the live `wasmMemory.buffer`, never from a cached HEAP
+                // view, so they stay valid after ALLOW_MEMORY_GROWTH has replaced the buffer.
+                // Async bodies outlive the borrow, so they get a copy taken before the first `await`.
+                ParamKind::Slice { array, .. } if sig.asyncness => {
+                    format!("{name} = new {array}(wasmMemory.buffer, {name}, {name}$len).slice(); ")
+                }
+                ParamKind::Slice { array, .. } => {
+                    format!("{name} = new {array}(wasmMemory.buffer, {name}, {name}$len); ")
+                }
+                // `release()` drops the Rust closure, after which calling it throws.
+                ParamKind::Closure { .. } => format!(
+                    "{name} = ((data, call, drop) => {{ \
+                        var f = (...args) => {{ \
+                            if (!data) throw new Error('closure was released'); \
+                            return getWasmTableEntry(call)(data, ...args); \
+                        }}; \
+                        f.release = () => {{ \
+                            if (data) {{ var d = data; data = 0; getWasmTableEntry(drop)(d); }} \
+                        }}; \
+                        return f; \
+                    }})({name}, {name}$call, {name}$drop); "
+                ),
+            }
+        })
+        .collect()
+}
+
+/// The JS placed before the body: the prologue, and the start of the async function or the `try`
+/// of a function returning a `Result`.
+pub fn js_open(sig: &Signature) -> String {
+    let mut open = js_prologue(sig);
+    match sig.asyncify {
+        Some(Asyncify::Async) => open.push_str("return Asyncify.handleAsync(async () => { "),
+        Some(Asyncify::Sleep) => open.push_str("return Asyncify.handleSleep((wakeUp) => { "),
+        None => {}
+    }
+    if sig.asyncness {
+        open.push_str("(async () => { ");
+    } else if result_ok(&sig.ret).is_some() {
+        open.push_str("try { ");
+    }
+    open
+}
+
+/// The JS placed after the body. Async functions settle their Rust future through the `__resolve`
+/// and `__reject` trampolines, and functions returning a `Result` catch exceptions, in both cases
+/// passing the message and stack of the error as newly allocated strings.
+pub fn js_close(sig: &Signature) -> String {
+    let mut close = js_settle(sig);
+    if sig.asyncify.is_some() {
+        close.push_str(" }); ");
+    }
+    close
+}
+
+fn js_settle(sig: &Signature) -> String {
+    if sig.asyncness {
+        " })().then(\
+            (value) => getWasmTableEntry(__resolve)(__state, value), \
+            (e) => { \
+                var error = e instanceof Error; \
+                getWasmTableEntry(__reject)(__state, \
+                    stringToNewUTF8(error ? e.message : String(e)), \
+                    error && e.stack ? stringToNewUTF8(e.stack) : 0); \
+            }); "
+            .to_string()
+    } else if let Some(ok) = result_ok(&sig.ret) {
+        // The value returned with an error is ignored, but it must still convert to the wasm
+        // return type, and 64-bit integers only convert from BigInts.
+        let zero = if is_bigint(ok) { "0n" } else { "0" };
+        format!(
+            " }} catch (e) {{ \
+                var error = e instanceof Error; \
+                HEAPU32[__error >> 2] = stringToNewUTF8(error ? e.message : String(e)); \
+                HEAPU32[(__error >> 2) + 1] = error && e.stack ? stringToNewUTF8(e.stack) : 0; \
+                return {zero}; \
+            }} "
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// JS library functions the lowered parameters rely on, in `EM_JS_DEPS` format.
+pub fn js_deps(sig: &Signature) -> Vec<&'static str> {
+    let mut deps = Vec::new();
+    if sig.asyncness
+        || sig
+            .params
+            .iter()
+            .any(|param| matches!(classify(&param.ty), ParamKind::Closure { .. }))
+    {
+        deps.push("$getWasmTableEntry");
+    }
+    if sig.asyncness || result_ok(&sig.ret).is_some() {
+        deps.push("$stringToNewUTF8");
+    }
+    deps
+}
+
+/// Declares the JS import and, when any parameter or the return value needs lowering, a Rust
+/// wrapper around it.
+pub fn import(name: &Ident, sig: &Signature) -> TokenStream {
+    let Signature {
+        asyncness,
+        params,
+        ret,
+        ..
+    } = sig;
+    let result = result_ok(ret);
+    let needs_wrapper = *asyncness
+        || result.is_some()
+        || params
+            .iter()
+            .any(|param| !matches!(classify(&param.ty), ParamKind::Scalar));
+
+    let link_name = js_name(name, sig);
+    if !needs_wrapper {
+        let names = params.iter().map(|param| &param.name);
+        let tys = params.iter().map(|param| &param.ty);
+        return quote! {
+            #[link(wasm_import_module = "env")]
+            #[allow(dead_code)]
+            unsafe extern "C" {
+                #[link_name = #link_name]
+                pub unsafe fn #name(#( #names : #tys ),*) #ret;
+            }
+        };
+    }
+
+    let mut generics = Vec::new();
+    let mut generic_names = Vec::new();
+    let mut wrapper_params = Vec::new();
+    let mut abi_params = Vec::new();
+    let mut abi_args = Vec::new();
+    let mut trampolines = Vec::new();
+    for (index, param) in params.iter().enumerate() {
+        let arg = &param.name;
+        let ty = &param.ty;
+        match classify(ty) {
+            ParamKind::Scalar => {
+                wrapper_params.push(quote! { #arg: #ty });
+                abi_params.push(quote! { #arg: #ty });
+                abi_args.push(quote! { #arg });
+            }
+            ParamKind::Slice { .. } => {
+                let len = format_ident!("{}_len", arg);
+                wrapper_params.push(quote! { #arg: #ty });
+                abi_params.push(quote! { #arg: *const ::std::ffi::c_void });
+                abi_params.push(quote! { #len: usize });
+                abi_args.push(quote! { #arg.as_ptr() as *const ::std::ffi::c_void });
+                abi_args.push(quote! { #arg.len() });
+            }
+            ParamKind::Closure { signature, bounds } => {
+                let closure_ty = match bounds {
+                    Some(bounds) => {
+                        let generic = format_ident!("__F{}", index);
+                        generics.push(quote! { #generic: #bounds + 'static });
+                        generic_names.push(generic.clone());
+                        quote! { #generic }
+                    }
+                    None => quote! { #ty },
+                };
+                wrapper_params.push(quote! { #arg: #closure_ty });
+
+                let call = format_ident!("{}_call", arg);
+                let drop = format_ident!("{}_drop", arg);
+                let inputs = signature.inputs.iter().collect::<Vec<_>>();
+                let output = &signature.output;
+                let input_names = (0..inputs.len())
+                    .map(|i| format_ident!("arg{}", i))
+                    .collect::<Vec<_>>();
+                trampolines.push(quote! {
+                    unsafe extern "C" fn #call<F: FnMut(#( #inputs ),*) #output>(
+                        data: *mut ::std::ffi::c_void,
+                        #( #input_names: #inputs ),*
+                    ) #output {
+                        // JS may call the closure again from inside itself, which would alias the
+                        // `&mut F`, so reentrant calls throw to JS instead.
+                        let cell = unsafe { &*(data as *const ::std::cell::RefCell<F>) };
+                        let Ok(mut f) = cell.try_borrow_mut() else {
+                            unsafe {
+                                ::emscripten_rs_sys::emscripten_throw_string(
+                                    c"a js! closure was called while it was running".as_ptr(),
+                                )
+                            };
+                            ::std::process::abort();
+                        };
+                        (*f)(#( #input_names ),*)
+                    }
+
+                    unsafe extern "C" fn #drop<F>(data: *mut ::std::ffi::c_void) {
+                        let cell = unsafe { ::std::boxed::Box::from_raw(data as *mut ::std::cell::RefCell<F>) };
+                        if cell.try_borrow_mut().is_err() {
+                            ::std::mem::forget(cell);
+                            unsafe {
+                                ::emscripten_rs_sys::emscripten_throw_string(
+                                    c"a js! closure was released while it was running".as_ptr(),
+                                )
+                            };
+                            ::std::process::abort();
+                        }
+                    }
+                });
+
+                abi_params.push(quote! { #arg: *mut ::std::ffi::c_void });
+                abi_params.push(quote! {
+                    #call: unsafe extern "C" fn(*mut ::std::ffi::c_void, #( #inputs ),*) #output
+                });
+                abi_params.push(quote! { #drop: unsafe extern "C" fn(*mut ::std::ffi::c_void) });
+                abi_args.push(quote! {
+                    ::std::boxed::Box::into_raw(::std::boxed::Box::new(::std::cell::RefCell::new(#arg)))
+                        as *mut ::std::ffi::c_void
+                });
+                abi_args.push(quote! { #call::<#closure_ty> });
+                abi_args.push(quote! { #drop::<#closure_ty> });
+            }
+        }
+    }
+
+    let is_unit =
+        |ty: &Type| matches!(strip_groups(ty), Type::Tuple(unit) if unit.elems.is_empty());
+
+    if *asyncness {
+        let (ok, output) = match (result, ret) {
+            (Some(ok), ReturnType::Type(_, ty)) => (quote! { #ok }, quote! { #ty }),
+            (_, ReturnType::Type(_, ty)) => (quote! { #ty }, quote! { #ty }),
+            (_, ReturnType::Default) => (quote! { () }, quote! { () }),
+        };
+        let ok_is_unit = match (result, ret) {
+            (Some(ok), _) => is_unit(ok),
+            (None, ReturnType::Type(_, ty)) => is_unit(ty),
+            (None, ReturnType::Default) => true,
+        };
+        let (value_param, value) = if ok_is_unit {
+            (quote! {}, quote! { () })
+        } else {
+            (quote! { , value: #ok }, quote! { value })
+        };
+        abi_params.push(quote! { __state: *const ::std::ffi::c_void });
+        abi_params.push(quote! {
+            __resolve: unsafe extern "C" fn(*const ::std::ffi::c_void #value_param)
+        });
+        abi_params.push(quote! {
+            __reject: unsafe extern "C" fn(
+                *const ::std::ffi::c_void,
+                *mut ::std::ffi::c_char,
+                *mut ::std::ffi::c_char,
+            )
+        });
+        abi_args.push(quote! { future.raw_state() });
+        abi_args.push(quote! { resolve });
+        abi_args.push(quote! { reject });
+        // A rejection becomes a panic unless the function returns a `Result`.
+        let output_future = if result.is_some() {
+            quote! { future }
+        } else {
+            quote! {
+                async move {
+                    match future.await {
+                        Ok(value) => value,
+                        Err(error) => panic!("{}", error),
+                    }
+                }
+            }
+        };
+
+        // Slices are copied before the import returns, so the future does not borrow them.
+        return quote! {
+            #[allow(dead_code)]
+            pub unsafe fn #name<#( #generics ),*>(
+                #( #wrapper_params ),*
+            ) -> impl ::std::future::Future<Output = #output> + use<#( #generic_names ),*> {
+                #[link(wasm_import_module = "env")]
+                unsafe extern "C" {
+                    #[link_name = #link_name]
+                    unsafe fn import(#( #abi_params ),*);
+                }
+
+                #( #trampolines )*
+
+                unsafe extern "C" fn resolve(state: *const ::std::ffi::c_void #value_param) {
+                    unsafe { ::emscripten_rs_sys::em_js::JsFuture::<#ok>::settle(state, Ok(#value)) }
+                }
+
+                unsafe extern "C" fn reject(
+                    state: *const ::std::ffi::c_void,
+                    message: *mut ::std::ffi::c_char,
+                    stack: *mut ::std::ffi::c_char,
+                ) {
+                    unsafe {
+                        let error = ::emscripten_rs_sys::em_js::JsError::from_raw([message, stack]);
+                        ::emscripten_rs_sys::em_js::JsFuture::<#ok>::settle(state, Err(error))
+                    }
+                }
+
+                let future = ::emscripten_rs_sys::em_js::JsFuture::<#ok>::new();
+                unsafe { import(#( #abi_args ),*) };
+                #output_future
+            }
+        };
+    }
+
+    let (abi_ret, call) = match result {
+        Some(ok) => {
+            abi_params.push(quote! { __error: *mut [*mut ::std::ffi::c_char; 2] });
+            abi_args.push(quote! { &mut error });
+            let abi_ret = if is_unit(ok) {
+                quote! {}
+            } else {
+                quote! { -> #ok }
+            };
+            (
+                abi_ret,
+                quote! {
+                    let mut error = [::std::ptr::null_mut(); 2];
+                    let value = unsafe { import(#( #abi_args ),*) };
+                    if error[0].is_null() {
+                        Ok(value)
+                    } else {
+                        Err(unsafe { ::emscripten_rs_sys::em_js::JsError::from_raw(error) })
+                    }
+                },
+            )
+        }
+        None => (
+            quote! { #ret },
+            quote! { unsafe { import(#( #abi_args ),*) } },
+        ),
+    };
+
+    quote! {
+        #[allow(dead_code)]
+        pub unsafe fn #name<#( #generics ),*>(#( #wrapper_params ),*) #ret {
+            #[link(wasm_import_module = "env")]
+            unsafe extern "C" {
+                #[link_name = #link_name]
+                unsafe fn import(#( #abi_params ),*) #abi_ret;
+            }
+
+            #( #trampolines )*
+
+            #call
+        }
+    }
+}
+
+/// Where calls to a `js!` function run in pthread builds, set with `#[proxy(sync)]` or
+/// `#[proxy(async)]`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Proxy {
+    /// Blocks until the main runtime thread has run the function, and returns its result.
+    Sync,
+    /// Queues the function on the main runtime thread and returns immediately.
+    Async,
+}
+
+/// How a `js!` function marked with `#[asyncify]` suspends the wasm stack while its JS runs.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Asyncify {
+    /// `#[asyncify]`: the body is an async function run by `Asyncify.handleAsync`.
+    Async,
+    /// `#[asyncify(sleep)]`: the body is run by `Asyncify.handleSleep` and calls `wakeUp`.
+    Sleep,
+}
+
+/// Reads a mode from the arguments of an attribute, accepting keywords such as `async`.
+fn attr_mode(attr: &Attribute) -> Result<Ident> {
+    attr.parse_args::<Ident>().or_else(|_| {
+        // `async` is a keyword, so it does not parse as an identifier.
+        attr.parse_args::<Token![async]>()
+            .map(|token| Ident::new("async", token.span))
+    })
+}
+
+/// Reads the proxying and asyncify modes from the attributes of a `js!` function.
+pub fn parse_attrs(attrs: &[Attribute]) -> Result<(Option<Proxy>, Option<Asyncify>)> {
+    let mut proxy = None;
+    let mut asyncify = None;
+    for attr in attrs {
+        if attr.path().is_ident("proxy") {
+            let mode = attr_mode(attr)?;
+            proxy = Some(match mode.to_string().as_str() {
+                "sync" => Proxy::Sync,
+                "async" => Proxy::Async,
+                _ => return Err(Error::new(mode.span(), "expected `sync` or `async`")),
+            });
+        } else if attr.path().is_ident("asyncify") {
+            asyncify = Some(match &attr.meta {
+                Meta::Path(_) => Asyncify::Async,
+                _ => match attr_mode(attr)?.to_string().as_str() {
+                    "async" => Asyncify::Async,
+                    "sleep" => Asyncify::Sleep,
+                    _ => return Err(Error::new_spanned(attr, "expected `async` or `sleep`")),
+                },
+            });
+        } else {
+            return Err(Error::new_spanned(
+                attr,
+                "unsupported attribute, expected `#[proxy(sync|async)]` or `#[asyncify]`",
+            ));
+        }
+    }
+    Ok((proxy, asyncify))
+}
+
+/// Declares the Rust side of a `js!` function, proxied to the main runtime thread if requested.
+pub fn declare(name: &Ident, sig: &Signature) -> Result<TokenStream> {
+    let Signature {
+        proxy,
+        asyncify,
+        asyncness,
+        params,
+        ret,
+    } = sig;
+
+    if let Some(asyncify) = asyncify {
+        if *asyncness || proxy.is_some() {
+            return Err(Error::new(
+                name.span(),
+                "asyncify imports suspend the calling thread and cannot be async or proxied",
+            ));
+        }
+        if *asyncify == Asyncify::Sleep && result_ok(ret).is_some() {
+            return Err(Error::new_spanned(
+                ret,
+                "`#[asyncify(sleep)]` functions cannot catch exceptions, use `#[asyncify]` instead",
+            ));
+        }
+    }
+
+    if *asyncness
+        && let Some(param) = params
+            .iter()
+            .find(|param| matches!(classify(&param.ty), ParamKind::Slice { mutable: true, .. }))
+    {
+        return Err(Error::new_spanned(
+            &param.ty,
+            "async functions get a copy of their slices and cannot take `&mut` slices",
+        ));
+    }
+
+    let Some(proxy) = *proxy else {
+        return Ok(import(name, sig));
+    };
+
+    if *asyncness {
+        return Err(Error::new(
+            name.span(),
+            "async functions settle on the thread that called them and cannot be proxied",
+        ));
+    }
+
+    if proxy == Proxy::Async {
+        if let ReturnType::Type(_, ty) = ret {
+            return Err(Error::new_spanned(
+                ty,
+                "functions proxied with `#[proxy(async)]` cannot return a value",
+            ));
+        }
+        if let Some(param) = params
+            .iter()
+            .find(|param| matches!(classify(&param.ty), ParamKind::Slice { .. }))
+        {
+            return Err(Error::new_spanned(
+                &param.ty,
+                "functions proxied with `#[proxy(async)]` cannot borrow slices",
+            ));
+        }
+    }
+
+    // The closures move to the main runtime thread, where JS can keep them after the call.
+    if let Some(param) = params.iter().find(|param| {
+        matches!(classify(&param.ty), ParamKind::Closure { bounds: None, .. })
+            && !boxed_closure_is_send(&param.ty)
+    }) {
+        return Err(Error::new_spanned(
+            &param.ty,
+            "closures passed to proxied functions must be `Send`, e.g. `Box<dyn FnMut() + Send>`",
+        ));
+    }
+
+    let mut generics = Vec::new();
+    let mut wrapper_params = Vec::new();
+    for (index, param) in params.iter().enumerate() {
+        let arg = &param.name;
+        let ty = &param.ty;
+        match classify(ty) {
+            ParamKind::Closure {
+                bounds: Some(bounds),
+                ..
+            } => {
+                let generic = format_ident!("__F{}", index);
+                let send = (!has_send(bounds)).then(|| quote! { + Send });
+                generics.push(quote! { #generic: #bounds #send + 'static });
+                wrapper_params.push(quote! { #arg: #generic });
+            }
+            _ => wrapper_params.push(quote! { #arg: #ty }),
+        }
+    }
+    let names = params.iter().map(|param| &param.name).collect::<Vec<_>>();
+    let proxy_fn = match proxy {
+        Proxy::Sync => quote! { proxy_sync },
+        Proxy::Async => quote! { proxy_async },
+    };
+
+    // The import declared inside the wrapper shadows it, so the closure calls the JS function.
+    let import = import(name, sig);
+    Ok(quote! {
+        #[allow(dead_code)]
+        pub unsafe fn #name<#( #generics ),*>(#( #wrapper_params ),*) #ret {
+            #import
+
+            unsafe {
+                ::emscripten_rs_sys::em_js::#proxy_fn(move || unsafe { #name(#( #names ),*) })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declare_str(sig: &str) -> Result<TokenStream> {
+        declare(&format_ident!("f"), &parse_str(sig)?)
+    }
+
+    #[test]
+    fn test_result_catch_value() {
+        let sig = parse_str("[(text: *const c_char) -> Result<i64, JsError>]").unwrap();
+        assert!(js_close(&sig).contains("return 0n;"));
+        let sig = parse_str("[() -> Result<f64, JsError>]").unwrap();
+        assert!(js_close(&sig).contains("return 0;"));
+    }
+
+    #[test]
+    fn test_proxied_closures() {
+        let error = declare_str("[#[proxy(sync)] (f: Box<dyn FnMut(i32)>)]").unwrap_err();
+        assert!(error.to_string().contains("must be `Send`"));
+        assert!(declare_str("[#[proxy(sync)] (f: Box<dyn FnMut(i32) + Send>)]").is_ok());
+
+        let expanded = declare_str("[#[proxy(sync)] (f: impl FnMut(i32))]").unwrap();
+        assert!(
+            expanded
+                .to_string()
+                .contains("__F0 : FnMut (i32) + Send + 'static")
+        );
+        let expanded = declare_str("[#[proxy(sync)] (f: impl FnMut(i32) + Send)]").unwrap();
+        assert!(!expanded.to_string().contains("Send + Send"));
+    }
+}
@@ -0,0 +1,154 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+
+/// JS source rebuilt from Rust tokens, remembering which token produced each byte range.
+#[derive(Default)]
+struct Source {
+    text: String,
+    spans: Vec<(usize, Span)>,
+    joint: bool,
+}
+
+impl Source {
+    fn push(&mut self, token: &str, span: Span) {
+        if !self.text.is_empty() && !self.joint {
+            self.text.push(' ');
+        }
+        self.joint = false;
+        self.spans.push((self.text.len(), span));
+        self.text.push_str(token);
+    }
+
+    fn push_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+                    if !open.is_empty() {
+                        self.push(open, group.span_open());
+                    }
+                    self.push_tokens(group.stream());
+                    if !close.is_empty() {
+                        self.push(close, group.span_close());
+                    }
+                }
+                TokenTree::Punct(punct) => {
+                    self.push(&punct.as_char().to_string(), punct.span());
+                    self.joint = punct.spacing() == Spacing::Joint;
+                }
+                TokenTree::Ident(ident) => self.push(&ident.to_string(), ident.span()),
+                TokenTree::Literal(literal) => self.push(&literal.to_string(), literal.span()),
+            }
+        }
+    }
+
+    /// The span of the token at or before `offset`.
+    fn span_at(&self, offset: usize) -> Span {
+        let index = self.spans.partition_point(|(start, _)| *start <= offset);
+        self.spans
+            .get(index.saturating_sub(1))
+            .map(|(_, span)| *span)
+            .unwrap_or_else(Span::call_site)
+    }
+}
+
+/// Parses `body` as the body of a JS function taking `params`, returning each syntax error with
+/// its byte offset into `body`.
+fn parse_errors(params: &[String], asyncness: bool, body: &str) -> Vec<(usize, String)> {
+    let keyword = if asyncness {
+        "async function"
+    } else {
+        "function"
+    };
+    let prefix = format!("{keyword} __js({}) {{\n", params.join(", "));
+    let text = format!("{prefix}{body}\n}}");
+
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, &text, SourceType::cjs()).parse();
+
+    parsed
+        .errors
+        .iter()
+        .map(|error| {
+            let offset = error
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map(|label| label.offset().saturating_sub(prefix.len()))
+                .unwrap_or(0);
+            (offset, format!("JS syntax error: {}", error.message))
+        })
+        .collect()
+}
+
+/// Checks a body written as Rust tokens, and reports syntax errors at the tokens of the macro
+/// invocation that caused them.
+pub fn check(params: &[String], asyncness: bool, body: TokenStream) -> Option<syn::Error> {
+    let mut source = Source::default();
+    source.push_tokens(body);
+
+    parse_errors(params, asyncness, &source.text)
+        .into_iter()
+        .map(|(offset, message)| syn::Error::new(source.span_at(offset), message))
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+}
+
+/// Checks a body given as source text, returning the first syntax error with its line in the text.
+pub fn check_text(params: &[String], asyncness: bool, body: &str) -> Option<String> {
+    parse_errors(params, asyncness, body)
+        .into_iter()
+        .next()
+        .map(|(offset, message)| {
+            let line = body.as_bytes()[..offset.min(body.len())]
+                .iter()
+                .filter(|&&byte| byte == b'\n')
+                .count()
+                + 1;
+            format!("{message} (line {line} of the JS source)")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> Vec<String> {
+        vec!["x".to_string()]
+    }
+
+    #[test]
+    fn test_check_valid() {
+        let body = "var y = x * 2; return y;".parse().unwrap();
+        assert!(check(&params(), false, body).is_none());
+        let body = "return await x;".parse().unwrap();
+        assert!(check(&params(), true, body).is_none());
+        assert!(check_text(&params(), false, "// comment\nreturn `${x}`;").is_none());
+    }
+
+    #[test]
+    fn test_check_error() {
+        let body = "var y = x;\nreturn y + ;".parse().unwrap();
+        let error = check(&params(), false, body).unwrap();
+        assert!(error.to_string().starts_with("JS syntax error"));
+        // Reported at the `;` that ends the expression early.
+        let start = error.span().start();
+        assert_eq!((start.line, start.column), (2, 11));
+    }
+
+    #[test]
+    fn test_check_text_error() {
+        let error = check_text(&params(), false, "var y = x;\n\nreturn y + ;").unwrap();
+        assert!(error.starts_with("JS syntax error"));
+        assert!(error.ends_with("(line 3 of the JS source)"));
+    }
+}
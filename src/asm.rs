@@ -0,0 +1,265 @@
+//! Support for `EM_ASM` style JS snippets, which are stored in the `em_asm` section and called
+//! through the `emscripten_asm_const_*` family of functions.
+
+use std::ffi::{c_char, c_int, c_void};
+
+/// The `emscripten_asm_const_*` variant that returns a given Rust type.
+pub enum AsmReturn {
+    Int,
+    Double,
+    Ptr,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A value that can be passed through the variadic arguments of `emscripten_asm_const_*`.
+pub trait AsmAbi: sealed::Sealed + Copy {
+    /// The character describing the value in the argument signature string.
+    const SIG: u8;
+}
+
+macro_rules! impl_asm_abi {
+    ($($ty:ty => $sig:literal),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl AsmAbi for $ty {
+                const SIG: u8 = $sig;
+            }
+        )*
+    };
+}
+
+impl_asm_abi!(c_int => b'i', i64 => b'j', f64 => b'd', *const c_void => b'p');
+
+/// A Rust type that can be passed as an argument to an `EM_ASM` snippet.
+pub trait AsmArg {
+    type Abi: AsmAbi;
+    fn into_abi(self) -> Self::Abi;
+}
+
+macro_rules! impl_asm_arg {
+    ($abi:ty: $($ty:ty)*) => {
+        $(
+            impl AsmArg for $ty {
+                type Abi = $abi;
+                fn into_abi(self) -> $abi {
+                    self as $abi
+                }
+            }
+        )*
+    };
+}
+
+// `u32` travels as a double so that values above `i32::MAX` stay positive in JS.
+impl_asm_arg!(c_int: i8 u8 i16 u16 i32 bool);
+impl_asm_arg!(i64: i64 u64);
+impl_asm_arg!(f64: f32 f64 u32);
+impl_asm_arg!(*const c_void: usize isize);
+
+impl<T> AsmArg for *const T {
+    type Abi = *const c_void;
+    fn into_abi(self) -> *const c_void {
+        self as _
+    }
+}
+
+impl<T> AsmArg for *mut T {
+    type Abi = *const c_void;
+    fn into_abi(self) -> *const c_void {
+        self as _
+    }
+}
+
+/// A tuple of lowered arguments, with the null terminated signature string describing it.
+pub trait AsmArgs {
+    const SIG: &'static [u8];
+}
+
+macro_rules! impl_asm_args {
+    ($($arg:ident)*) => {
+        impl<$($arg: AsmAbi),*> AsmArgs for ($($arg,)*) {
+            const SIG: &'static [u8] = &[$($arg::SIG,)* 0];
+        }
+    };
+}
+
+impl_asm_args!();
+impl_asm_args!(A);
+impl_asm_args!(A B);
+impl_asm_args!(A B C);
+impl_asm_args!(A B C D);
+impl_asm_args!(A B C D E);
+impl_asm_args!(A B C D E F);
+impl_asm_args!(A B C D E F G);
+impl_asm_args!(A B C D E F G H);
+impl_asm_args!(A B C D E F G H I);
+impl_asm_args!(A B C D E F G H I J);
+impl_asm_args!(A B C D E F G H I J K);
+impl_asm_args!(A B C D E F G H I J K L);
+impl_asm_args!(A B C D E F G H I J K L M);
+impl_asm_args!(A B C D E F G H I J K L M N);
+impl_asm_args!(A B C D E F G H I J K L M N O);
+impl_asm_args!(A B C D E F G H I J K L M N O P);
+
+/// The signature string of the lowered arguments, e.g. `"ipd"`.
+pub fn signature<T: AsmArgs>(_args: &T) -> *const c_char {
+    T::SIG.as_ptr() as _
+}
+
+/// A Rust type that can be returned from an `EM_ASM` snippet.
+pub trait AsmRet {
+    const RETURN: AsmReturn;
+    fn from_int(value: c_int) -> Self;
+    fn from_double(value: f64) -> Self;
+    fn from_ptr(value: *mut c_void) -> Self;
+}
+
+impl AsmRet for () {
+    const RETURN: AsmReturn = AsmReturn::Int;
+    fn from_int(_: c_int) {}
+    fn from_double(_: f64) {}
+    fn from_ptr(_: *mut c_void) {}
+}
+
+impl AsmRet for bool {
+    const RETURN: AsmReturn = AsmReturn::Int;
+    fn from_int(value: c_int) -> Self {
+        value != 0
+    }
+    fn from_double(value: f64) -> Self {
+        value != 0.0
+    }
+    fn from_ptr(value: *mut c_void) -> Self {
+        !value.is_null()
+    }
+}
+
+macro_rules! impl_asm_ret {
+    ($return:ident: $($ty:ty)*) => {
+        $(
+            impl AsmRet for $ty {
+                const RETURN: AsmReturn = AsmReturn::$return;
+                fn from_int(value: c_int) -> Self {
+                    value as _
+                }
+                fn from_double(value: f64) -> Self {
+                    value as _
+                }
+                fn from_ptr(value: *mut c_void) -> Self {
+                    value as usize as _
+                }
+            }
+        )*
+    };
+}
+
+impl_asm_ret!(Int: i8 u8 i16 u16 i32 u32);
+impl_asm_ret!(Double: f32 f64);
+impl_asm_ret!(Ptr: usize isize);
+
+impl<T> AsmRet for *const T {
+    const RETURN: AsmReturn = AsmReturn::Ptr;
+    fn from_int(value: c_int) -> Self {
+        value as usize as _
+    }
+    fn from_double(value: f64) -> Self {
+        value as usize as _
+    }
+    fn from_ptr(value: *mut c_void) -> Self {
+        value as _
+    }
+}
+
+impl<T> AsmRet for *mut T {
+    const RETURN: AsmReturn = AsmReturn::Ptr;
+    fn from_int(value: c_int) -> Self {
+        value as usize as _
+    }
+    fn from_double(value: f64) -> Self {
+        value as usize as _
+    }
+    fn from_ptr(value: *mut c_void) -> Self {
+        value as _
+    }
+}
+
+/// Runs a JS snippet, the equivalent of the `EM_ASM` family of C macros.
+///
+/// The code is a string literal that refers to its arguments as `$0`, `$1`, ... Arguments are
+/// passed by value and described to JS by a signature string built from their types. The return
+/// type, given after `->` and `()` by default, selects the matching `emscripten_asm_const_*`
+/// function.
+///
+/// ```ignore
+/// em_asm!("console.log($0, $1)", 1, 2.5);
+/// let len = em_asm!(-> u32, "return UTF8ToString($0).length", c"hello".as_ptr());
+/// ```
+///
+/// In pthread builds the snippet can run on the main runtime thread instead of the calling one:
+/// `sync_on_main_thread` blocks until it returns, `async_on_main_thread` queues it and returns
+/// immediately, and cannot have a return value.
+///
+/// ```ignore
+/// let width = em_asm!(sync_on_main_thread -> f64, "return window.innerWidth");
+/// em_asm!(async_on_main_thread, "document.title = 'ready'");
+/// ```
+///
+/// Storing the code in the `em_asm` section needs inline assembly, so this macro is only available
+/// on nightly toolchains.
+#[cfg(em_js_nightly)]
+pub use emscripten_rs_macros::em_asm;
+
+#[cfg(all(test, em_js_nightly))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        let args = (
+            1u8.into_abi(),
+            2u32.into_abi(),
+            3u64.into_abi(),
+            std::ptr::null::<u8>().into_abi(),
+        );
+        let sig = unsafe { std::ffi::CStr::from_ptr(signature(&args)) };
+        assert_eq!(sig, c"idjp");
+        assert_eq!(unsafe { *signature(&()) }, 0);
+    }
+
+    #[test]
+    fn test_em_asm() {
+        em_asm!("globalThis.emAsmValue = $0 + $1", 20, 22);
+        assert_eq!(em_asm!(-> i32, "return globalThis.emAsmValue"), 42);
+        assert_eq!(em_asm!(-> f64, "return $0 * $1", 1.5f32, 4.0), 6.0);
+        assert_eq!(em_asm!(-> u32, "return $0", u32::MAX), u32::MAX);
+        assert!(em_asm!(-> bool, "return $0 === 2n ** 40n", 1i64 << 40));
+
+        let text = c"hello";
+        let ptr = em_asm!(-> *const u8, "return $0 + 1", text.as_ptr());
+        assert_eq!(ptr, text.as_ptr().wrapping_add(1) as *const u8);
+        assert_eq!(
+            em_asm!(-> usize, "return UTF8ToString($0).length", text.as_ptr()),
+            5
+        );
+    }
+
+    macro_rules! seven {
+        () => {
+            em_asm!(-> i32, "return 7")
+        };
+    }
+
+    #[test]
+    fn test_same_call_site() {
+        assert_eq!(seven!() + seven!(), 14);
+    }
+
+    #[test]
+    fn test_em_asm_on_main_thread() {
+        let value = em_asm!(sync_on_main_thread -> i32, "return $0 * 2", 21);
+        assert_eq!(value, 42);
+        em_asm!(async_on_main_thread, "globalThis.emAsmAsync = $0", 1);
+    }
+}
@@ -0,0 +1,196 @@
+//! Safe wrappers for the synchronous emscripten APIs that suspend the wasm stack, which only work
+//! when the program is linked with `-sASYNCIFY` or `-sJSPI`.
+//!
+//! Every function checks [`emscripten_has_asyncify`] first and returns
+//! [`AsyncifyError::Unavailable`] instead of aborting the program when stack switching is missing.
+
+use crate::*;
+use std::{
+    error::Error,
+    ffi::{CStr, c_int, c_void},
+    fmt,
+    time::Duration,
+};
+
+/// How the program was linked to suspend the wasm stack, as reported by `emscripten_has_asyncify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsyncifyMode {
+    /// `-sASYNCIFY`, which instruments the wasm code to unwind and rewind its stack.
+    Asyncify,
+    /// `-sJSPI`, which uses the JS Promise Integration proposal of the engine.
+    Jspi,
+}
+
+/// The stack switching mode the program was linked with, if any.
+pub fn mode() -> Option<AsyncifyMode> {
+    match unsafe { emscripten_has_asyncify() } {
+        0 => None,
+        2 => Some(AsyncifyMode::Jspi),
+        _ => Some(AsyncifyMode::Asyncify),
+    }
+}
+
+/// Whether blocking calls such as [`sleep`] can suspend the wasm stack.
+pub fn has_asyncify() -> bool {
+    mode().is_some()
+}
+
+/// An error returned by the blocking helpers of this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsyncifyError {
+    /// The program was linked without `-sASYNCIFY` or `-sJSPI`.
+    Unavailable,
+    /// The operation ran, but emscripten reported a failure.
+    Failed,
+}
+
+impl fmt::Display for AsyncifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "the program was linked without -sASYNCIFY or -sJSPI"),
+            Self::Failed => write!(f, "the blocking emscripten call failed"),
+        }
+    }
+}
+
+impl Error for AsyncifyError {}
+
+fn check() -> Result<(), AsyncifyError> {
+    if has_asyncify() {
+        Ok(())
+    } else {
+        Err(AsyncifyError::Unavailable)
+    }
+}
+
+fn check_error(error: c_int) -> Result<(), AsyncifyError> {
+    if error == 0 {
+        Ok(())
+    } else {
+        Err(AsyncifyError::Failed)
+    }
+}
+
+/// Copies a buffer allocated by emscripten into a `Vec` and frees it.
+unsafe fn take_buffer(buffer: *mut c_void, len: c_int) -> Vec<u8> {
+    if buffer.is_null() {
+        return Vec::new();
+    }
+    let data = unsafe { std::slice::from_raw_parts(buffer as *const u8, len as usize) }.to_vec();
+    unsafe { emscripten_builtin_free(buffer) };
+    data
+}
+
+/// Suspends the calling thread for `duration`, letting the browser event loop run meanwhile.
+pub fn sleep(duration: Duration) -> Result<(), AsyncifyError> {
+    check()?;
+    unsafe { emscripten_sleep(duration.as_millis().min(u32::MAX as u128) as u32) };
+    Ok(())
+}
+
+/// Downloads `url` into the file `file` of the emscripten file system.
+pub fn wget(url: &CStr, file: &CStr) -> Result<(), AsyncifyError> {
+    check()?;
+    check_error(unsafe { emscripten_wget(url.as_ptr(), file.as_ptr()) })
+}
+
+/// Downloads `url` into memory.
+pub fn wget_data(url: &CStr) -> Result<Vec<u8>, AsyncifyError> {
+    check()?;
+    let mut buffer = std::ptr::null_mut();
+    let mut len = 0;
+    let mut error = 0;
+    unsafe { emscripten_wget_data(url.as_ptr(), &mut buffer, &mut len, &mut error) };
+    check_error(error)?;
+    Ok(unsafe { take_buffer(buffer, len) })
+}
+
+/// Loads the file `file_id` from the IndexedDB database `db_name`.
+pub fn idb_load(db_name: &CStr, file_id: &CStr) -> Result<Vec<u8>, AsyncifyError> {
+    check()?;
+    let mut buffer = std::ptr::null_mut();
+    let mut len = 0;
+    let mut error = 0;
+    unsafe {
+        emscripten_idb_load(
+            db_name.as_ptr(),
+            file_id.as_ptr(),
+            &mut buffer,
+            &mut len,
+            &mut error,
+        )
+    };
+    check_error(error)?;
+    Ok(unsafe { take_buffer(buffer, len) })
+}
+
+/// Stores `data` as the file `file_id` in the IndexedDB database `db_name`.
+pub fn idb_store(db_name: &CStr, file_id: &CStr, data: &[u8]) -> Result<(), AsyncifyError> {
+    check()?;
+    let len = c_int::try_from(data.len()).map_err(|_| AsyncifyError::Failed)?;
+    let mut error = 0;
+    unsafe {
+        emscripten_idb_store(
+            db_name.as_ptr(),
+            file_id.as_ptr(),
+            data.as_ptr() as *mut c_void,
+            len,
+            &mut error,
+        )
+    };
+    check_error(error)
+}
+
+/// Deletes the file `file_id` from the IndexedDB database `db_name`.
+pub fn idb_delete(db_name: &CStr, file_id: &CStr) -> Result<(), AsyncifyError> {
+    check()?;
+    let mut error = 0;
+    unsafe { emscripten_idb_delete(db_name.as_ptr(), file_id.as_ptr(), &mut error) };
+    check_error(error)
+}
+
+/// Whether the file `file_id` exists in the IndexedDB database `db_name`.
+pub fn idb_exists(db_name: &CStr, file_id: &CStr) -> Result<bool, AsyncifyError> {
+    check()?;
+    let mut exists = 0;
+    let mut error = 0;
+    unsafe { emscripten_idb_exists(db_name.as_ptr(), file_id.as_ptr(), &mut exists, &mut error) };
+    check_error(error)?;
+    Ok(exists != 0)
+}
+
+/// Removes every file from the IndexedDB database `db_name`.
+pub fn idb_clear(db_name: &CStr) -> Result<(), AsyncifyError> {
+    check()?;
+    let mut error = 0;
+    unsafe { emscripten_idb_clear(db_name.as_ptr(), &mut error) };
+    check_error(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The `asyncify` feature links the tests with -sASYNCIFY.
+    #[cfg(not(feature = "asyncify"))]
+    #[test]
+    fn test_unavailable() {
+        assert_eq!(mode(), None);
+        assert_eq!(
+            sleep(Duration::from_millis(1)),
+            Err(AsyncifyError::Unavailable)
+        );
+        assert_eq!(wget_data(c"data.bin"), Err(AsyncifyError::Unavailable));
+        assert_eq!(idb_exists(c"db", c"file"), Err(AsyncifyError::Unavailable));
+    }
+
+    #[cfg(feature = "asyncify")]
+    #[test]
+    fn test_sleep() {
+        assert_eq!(mode(), Some(AsyncifyMode::Asyncify));
+
+        let start = unsafe { emscripten_get_now() };
+        assert_eq!(sleep(Duration::from_millis(20)), Ok(()));
+        assert!(unsafe { emscripten_get_now() } - start >= 20.0);
+    }
+}
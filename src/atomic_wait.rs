@@ -0,0 +1,426 @@
+//! Waits on shared memory with `Atomics.waitAsync`, which never blocks the thread, and with the
+//! blocking futexes of `emscripten_futex_wait`. Needs shared memory, through the `pthreads` or the
+//! `wasm_workers` feature.
+//!
+//! ```no_run
+//! use emscripten_rs_sys::atomic_wait::*;
+//! use std::sync::atomic::{AtomicI32, Ordering};
+//!
+//! static READY: AtomicI32 = AtomicI32::new(0);
+//!
+//! async fn until_ready() {
+//!     while READY.load(Ordering::Acquire) == 0 {
+//!         wait_async(&READY, 0, None).await;
+//!     }
+//! }
+//! ```
+
+use crate::*;
+use std::{
+    cell::RefCell,
+    error::Error,
+    ffi::{c_int, c_void},
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    rc::Rc,
+    sync::atomic::{AtomicI32, AtomicU32},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+/// The outcome of [`wait_async`] and [`futex_wait`], an `ATOMICS_WAIT_RESULT_T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The wait was woken up by a notification.
+    Ok,
+    /// The value was not the expected one, so the wait did not start.
+    NotEqual,
+    /// No notification came within the timeout.
+    TimedOut,
+}
+
+impl WaitResult {
+    pub fn from_raw(result: c_int) -> Option<Self> {
+        match result {
+            ATOMICS_WAIT_OK => Some(Self::Ok),
+            ATOMICS_WAIT_NOT_EQUAL => Some(Self::NotEqual),
+            ATOMICS_WAIT_TIMED_OUT => Some(Self::TimedOut),
+            _ => None,
+        }
+    }
+}
+
+/// Waits until `atomic` is notified, if it holds `expected`, with `emscripten_atomic_wait_async`.
+/// `None` waits forever.
+///
+/// The wait completes when the thread returns to its event loop, so the future must be awaited on
+/// the thread that created it. Dropping the future cancels the wait with
+/// `emscripten_atomic_cancel_wait_async`, which also wakes up the other waiters on `atomic`.
+pub fn wait_async(
+    atomic: &AtomicI32,
+    expected: i32,
+    timeout: Option<Duration>,
+) -> impl Future<Output = WaitResult> + use<> {
+    let addr = atomic.as_ptr() as *mut c_void;
+    let max_wait = timeout.map_or(f64::INFINITY, |timeout| timeout.as_secs_f64() * 1000.0);
+    let wait = AsyncWait::start(
+        || {},
+        |user| {
+            Some(unsafe {
+                emscripten_atomic_wait_async(addr, expected as u32, Some(finish), user, max_wait)
+            })
+        },
+    );
+    async move {
+        let result = wait.await;
+        WaitResult::from_raw(result).expect("unknown ATOMICS_WAIT_RESULT_T")
+    }
+}
+
+// The errno values of the emscripten libc, which the futex functions return negated.
+const EAGAIN: c_int = 6;
+const EINVAL: c_int = 28;
+const ETIMEDOUT: c_int = 73;
+
+/// An error returned by [`futex_wait`] and [`futex_wake`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FutexError {
+    /// The calling thread is the main browser thread, and blocking there is not known to be
+    /// allowed by `-sALLOW_BLOCKING_ON_MAIN_THREAD`.
+    MainBrowserThread,
+    /// The address of the futex is invalid.
+    InvalidAddress,
+    /// Another error code was returned.
+    Failed(c_int),
+}
+
+impl fmt::Display for FutexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MainBrowserThread => {
+                write!(f, "blocking is not allowed on the main browser thread")
+            }
+            Self::InvalidAddress => write!(f, "invalid futex address"),
+            Self::Failed(error) => write!(f, "the futex call failed with error {error}"),
+        }
+    }
+}
+
+impl Error for FutexError {}
+
+fn futex_error(error: c_int) -> FutexError {
+    match -error {
+        EINVAL => FutexError::InvalidAddress,
+        _ => FutexError::Failed(error),
+    }
+}
+
+#[cfg(feature = "em_js")]
+mod js {
+    crate::js! {
+        fn _atomic_wait_rs_is_node() -> bool
+        {
+            return typeof ENVIRONMENT_IS_NODE != "undefined" && !!ENVIRONMENT_IS_NODE;
+        }
+    }
+}
+
+/// Whether the calling thread must not block, mirroring `emscripten_check_blocking_allowed`:
+/// workers and the main thread of Node may always block, the main browser thread only when the
+/// program was linked with `-sALLOW_BLOCKING_ON_MAIN_THREAD`.
+///
+/// The setting is only known with `-sRETAIN_COMPILER_SETTINGS`, and Node is only detected with the
+/// `em_js` feature. Otherwise the main thread conservatively refuses to block, as a wrong guess
+/// would abort the program.
+fn blocking_disallowed() -> bool {
+    if unsafe { emscripten_is_main_browser_thread() } == 0 {
+        return false;
+    }
+    #[cfg(feature = "em_js")]
+    {
+        if unsafe { js::_atomic_wait_rs_is_node() } {
+            return false;
+        }
+        compiler_setting::allow_blocking_on_main_thread() != Some(true)
+    }
+    #[cfg(not(feature = "em_js"))]
+    true
+}
+
+/// Blocks until `futex` is woken up with [`futex_wake`], if it holds `expected`, with
+/// `emscripten_futex_wait`. `None` waits forever.
+///
+/// The main browser thread busy-waits instead of using `Atomics.wait`. Returns
+/// [`FutexError::MainBrowserThread`] there instead of blocking unless the program was linked with
+/// `-sALLOW_BLOCKING_ON_MAIN_THREAD` and `-sRETAIN_COMPILER_SETTINGS`, as blocking where
+/// `emscripten_check_blocking_allowed` disallows it would abort. Workers and the main thread of Node
+/// always block, the latter only detected with the `em_js` feature.
+pub fn futex_wait(
+    futex: &AtomicU32,
+    expected: u32,
+    timeout: Option<Duration>,
+) -> Result<WaitResult, FutexError> {
+    if blocking_disallowed() {
+        return Err(FutexError::MainBrowserThread);
+    }
+    let max_wait = timeout.map_or(f64::INFINITY, |timeout| timeout.as_secs_f64() * 1000.0);
+    let result =
+        unsafe { emscripten_futex_wait(futex.as_ptr() as *mut c_void, expected, max_wait) };
+    match -result {
+        0 => Ok(WaitResult::Ok),
+        EAGAIN => Ok(WaitResult::NotEqual),
+        ETIMEDOUT => Ok(WaitResult::TimedOut),
+        _ => Err(futex_error(result)),
+    }
+}
+
+/// Wakes up to `count` threads waiting on `futex`, `u32::MAX` waking all of them, and returns
+/// the number of threads woken up. It never blocks, so it can be called from any thread.
+pub fn futex_wake(futex: &AtomicU32, count: u32) -> Result<u32, FutexError> {
+    let count = count.min(c_int::MAX as u32) as c_int;
+    let woken = unsafe { emscripten_futex_wake(futex.as_ptr() as *mut c_void, count) };
+    if woken < 0 {
+        Err(futex_error(woken))
+    } else {
+        Ok(woken as u32)
+    }
+}
+
+enum WaitState {
+    Waiting(Option<Waker>),
+    Finished(c_int),
+    Taken,
+    Dropped,
+}
+
+/// An async wait completed by an `ATOMICS_WAIT_RESULT_T` callback, which runs on the thread that
+/// started the wait.
+pub(crate) struct AsyncWait {
+    state: RefCell<WaitState>,
+    /// Gives back what a successful wait acquired after its future was dropped.
+    undo: Box<dyn Fn()>,
+}
+
+/// Resolves to the `ATOMICS_WAIT_RESULT_T` of an [`AsyncWait`].
+pub(crate) struct WaitFuture {
+    wait: Rc<AsyncWait>,
+    /// The token of a wait that can be cancelled with `emscripten_atomic_cancel_wait_async`.
+    token: Option<i32>,
+}
+
+impl AsyncWait {
+    /// Starts a wait with `start`, which receives the user data to pass with [`finish`] or
+    /// [`finish_volatile`], and returns the wait token if the wait can be cancelled.
+    pub(crate) fn start(
+        undo: impl Fn() + 'static,
+        start: impl FnOnce(*mut c_void) -> Option<i32>,
+    ) -> WaitFuture {
+        let wait = Rc::new(AsyncWait {
+            state: RefCell::new(WaitState::Waiting(None)),
+            undo: Box::new(undo),
+        });
+        let user = Rc::into_raw(wait.clone()) as *mut c_void;
+        let token = start(user);
+        if let Some(result) = token
+            && result > 0
+        {
+            // Not a token but `ATOMICS_WAIT_NOT_EQUAL` or `ATOMICS_WAIT_TIMED_OUT`, the callback
+            // will not run.
+            unsafe { complete(user, result) };
+        }
+        WaitFuture { wait, token }
+    }
+}
+
+unsafe fn complete(user: *mut c_void, result: c_int) {
+    let wait = unsafe { Rc::from_raw(user as *const AsyncWait) };
+    let state = mem::replace(&mut *wait.state.borrow_mut(), WaitState::Finished(result));
+    match state {
+        WaitState::Waiting(waker) => {
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+        WaitState::Dropped => {
+            *wait.state.borrow_mut() = WaitState::Dropped;
+            if result == ATOMICS_WAIT_OK {
+                (wait.undo)();
+            }
+        }
+        WaitState::Finished(_) | WaitState::Taken => unreachable!("an async wait finished twice"),
+    }
+}
+
+pub(crate) unsafe extern "C" fn finish(_: *mut i32, _: u32, result: c_int, user: *mut c_void) {
+    unsafe { complete(user, result) };
+}
+
+#[cfg(feature = "wasm_workers")]
+pub(crate) unsafe extern "C" fn finish_volatile(
+    _: *mut c_void,
+    _: u32,
+    result: c_int,
+    user: *mut c_void,
+) {
+    unsafe { complete(user, result) };
+}
+
+impl Future for WaitFuture {
+    type Output = c_int;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<c_int> {
+        let mut state = self.wait.state.borrow_mut();
+        match mem::replace(&mut *state, WaitState::Taken) {
+            WaitState::Finished(result) => Poll::Ready(result),
+            WaitState::Waiting(_) => {
+                *state = WaitState::Waiting(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            WaitState::Taken | WaitState::Dropped => {
+                panic!("polled an async wait after completion")
+            }
+        }
+    }
+}
+
+impl Drop for WaitFuture {
+    fn drop(&mut self) {
+        let state = mem::replace(&mut *self.wait.state.borrow_mut(), WaitState::Dropped);
+        match state {
+            WaitState::Waiting(_) => {
+                if let Some(token) = self.token
+                    && unsafe { emscripten_atomic_cancel_wait_async(token) }
+                        == EMSCRIPTEN_RESULT_SUCCESS as c_int
+                {
+                    // The callback will not run, release its reference.
+                    unsafe { drop(Rc::from_raw(Rc::as_ptr(&self.wait))) };
+                }
+            }
+            WaitState::Finished(ATOMICS_WAIT_OK) => (self.wait.undo)(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn test_async_wait() {
+        let undone = Rc::new(RefCell::new(0));
+        let start = |result| {
+            let undone = undone.clone();
+            let mut user = std::ptr::null_mut();
+            let wait = AsyncWait::start(
+                move || *undone.borrow_mut() += 1,
+                |data| {
+                    user = data;
+                    None
+                },
+            );
+            unsafe { complete(user, result) };
+            wait
+        };
+
+        let mut wait = start(ATOMICS_WAIT_TIMED_OUT);
+        assert_eq!(poll(&mut wait), Poll::Ready(ATOMICS_WAIT_TIMED_OUT));
+        drop(wait);
+        // Dropping an acquisition that succeeded gives it back.
+        drop(start(ATOMICS_WAIT_OK));
+        assert_eq!(*undone.borrow(), 1);
+
+        // So does an acquisition that succeeds after the drop.
+        let mut user = std::ptr::null_mut();
+        let undone_later = undone.clone();
+        let wait = AsyncWait::start(
+            move || *undone_later.borrow_mut() += 1,
+            |data| {
+                user = data;
+                None
+            },
+        );
+        drop(wait);
+        unsafe { complete(user, ATOMICS_WAIT_OK) };
+        assert_eq!(*undone.borrow(), 2);
+    }
+
+    #[test]
+    fn test_wait_result() {
+        assert_eq!(
+            WaitResult::from_raw(ATOMICS_WAIT_NOT_EQUAL),
+            Some(WaitResult::NotEqual)
+        );
+        assert_eq!(WaitResult::from_raw(3), None);
+    }
+
+    #[test]
+    fn test_futex_error() {
+        assert_eq!(futex_error(-EINVAL), FutexError::InvalidAddress);
+        assert_eq!(futex_error(-1), FutexError::Failed(-1));
+    }
+
+    // The tests run on the main thread of Node, which may block.
+    #[cfg(feature = "em_js")]
+    #[test]
+    fn test_futex_wait_main_thread() {
+        assert!(!blocking_disallowed());
+        let futex = AtomicU32::new(0);
+        assert_eq!(
+            futex_wait(&futex, 1, Some(Duration::ZERO)),
+            Ok(WaitResult::NotEqual)
+        );
+    }
+
+    #[cfg(feature = "pthreads")]
+    #[test]
+    fn test_futex_wait_wake() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        };
+
+        let futex = Arc::new(AtomicU32::new(0));
+        let waiting = Arc::new(AtomicBool::new(false));
+        let waiter = {
+            let futex = futex.clone();
+            let waiting = waiting.clone();
+            std::thread::spawn(move || {
+                assert_eq!(
+                    futex_wait(&futex, 1, Some(Duration::ZERO)),
+                    Ok(WaitResult::NotEqual)
+                );
+                assert_eq!(
+                    futex_wait(&futex, 0, Some(Duration::from_millis(1))),
+                    Ok(WaitResult::TimedOut)
+                );
+                waiting.store(true, Ordering::Release);
+                let mut results = Vec::new();
+                while futex.load(Ordering::Acquire) == 0 {
+                    results.push(futex_wait(&futex, 0, Some(Duration::from_secs(10))));
+                }
+                results
+            })
+        };
+        while !waiting.load(Ordering::Acquire) {
+            std::hint::spin_loop();
+        }
+        std::thread::sleep(Duration::from_millis(20));
+        futex.store(1, Ordering::Release);
+        futex_wake(&futex, u32::MAX).unwrap();
+
+        // The waiter either slept until the wake up, or saw the new value before waiting.
+        let results = waiter.join().unwrap();
+        assert!(
+            results
+                .iter()
+                .all(|result| matches!(result, Ok(WaitResult::Ok | WaitResult::NotEqual)))
+        );
+    }
+}
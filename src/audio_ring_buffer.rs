@@ -0,0 +1,311 @@
+//! A lock-free single producer, single consumer ring buffer of audio frames, to stream samples
+//! generated on the main thread or a worker into an
+//! [`AudioProcessor`](crate::audio_worklet::AudioProcessor).
+//!
+//! The buffer lives in the shared wasm memory, so the [`Producer`] and the [`Consumer`] can be
+//! used from any thread, including the audio worklet. Neither side blocks or allocates, a
+//! consumer finding too few frames outputs silence for the missing ones and counts an underrun.
+//!
+//! ```no_run
+//! use emscripten_rs_sys::{audio_ring_buffer::*, audio_worklet::*};
+//!
+//! struct Player(Consumer);
+//!
+//! impl AudioProcessor for Player {
+//!     fn process(&mut self, _: &[AudioBus], outputs: &mut [AudioBus], _: &[ParamValues]) -> bool {
+//!         self.0.pop_into(&mut outputs[0]);
+//!         true
+//!     }
+//! }
+//!
+//! let (mut producer, consumer) = ring_buffer(2, 4096);
+//! let player = Player(consumer);
+//! producer.push_interleaved(&[0.0; 256]);
+//! ```
+
+use crate::audio_worklet::AudioBus;
+use std::{
+    cell::UnsafeCell,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+};
+
+struct Shared {
+    channels: usize,
+    capacity: usize,
+    /// Interleaved frames.
+    samples: Box<[UnsafeCell<f32>]>,
+    /// The number of frames read and written since the creation, wrapping around. The power of two
+    /// capacity divides the range of `usize`, so the slot of a frame survives the wrap.
+    read: AtomicUsize,
+    write: AtomicUsize,
+    underruns: AtomicU64,
+    underrun_frames: AtomicU64,
+}
+
+// The frames between `read` and `write` are only accessed by the consumer, the others only by the
+// producer.
+unsafe impl Sync for Shared {}
+
+impl Shared {
+    fn len(&self) -> usize {
+        let write = self.write.load(Ordering::Acquire);
+        write.wrapping_sub(self.read.load(Ordering::Acquire))
+    }
+
+    fn underruns(&self) -> Underruns {
+        Underruns {
+            count: self.underruns.load(Ordering::Relaxed),
+            frames: self.underrun_frames.load(Ordering::Relaxed),
+        }
+    }
+
+    fn sample(&self, frame: usize, channel: usize) -> *mut f32 {
+        self.samples[(frame & (self.capacity - 1)) * self.channels + channel].get()
+    }
+}
+
+/// How often the consumer found fewer frames than it needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Underruns {
+    /// The number of reads that came up short.
+    pub count: u64,
+    /// The number of frames replaced with silence.
+    pub frames: u64,
+}
+
+/// Creates a ring buffer holding up to `capacity` frames of `channels` samples.
+///
+/// # Panics
+///
+/// Panics if `channels` is zero, or if `capacity` is not a power of two or too large for the
+/// address space.
+pub fn ring_buffer(channels: usize, capacity: usize) -> (Producer, Consumer) {
+    assert!(channels > 0, "a ring buffer needs at least one channel");
+    assert!(
+        capacity.is_power_of_two(),
+        "the capacity of a ring buffer must be a power of two"
+    );
+    assert!(capacity <= usize::MAX / 2 / channels);
+    let shared = Arc::new(Shared {
+        channels,
+        capacity,
+        samples: (0..channels * capacity)
+            .map(|_| UnsafeCell::new(0.0))
+            .collect(),
+        read: AtomicUsize::new(0),
+        write: AtomicUsize::new(0),
+        underruns: AtomicU64::new(0),
+        underrun_frames: AtomicU64::new(0),
+    });
+    (
+        Producer {
+            shared: shared.clone(),
+        },
+        Consumer { shared },
+    )
+}
+
+/// The writing end of a [`ring_buffer`].
+pub struct Producer {
+    shared: Arc<Shared>,
+}
+
+impl Producer {
+    pub fn channels(&self) -> usize {
+        self.shared.channels
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// The number of frames that can be pushed without dropping any.
+    pub fn free_frames(&self) -> usize {
+        self.shared.capacity - self.shared.len()
+    }
+
+    pub fn underruns(&self) -> Underruns {
+        self.shared.underruns()
+    }
+
+    fn push_frames(&mut self, frames: usize, sample: impl Fn(usize, usize) -> f32) -> usize {
+        let shared = &*self.shared;
+        let write = shared.write.load(Ordering::Relaxed);
+        let count = frames.min(self.free_frames());
+        for frame in 0..count {
+            for channel in 0..shared.channels {
+                let value = sample(frame, channel);
+                unsafe { *shared.sample(write.wrapping_add(frame), channel) = value };
+            }
+        }
+        shared
+            .write
+            .store(write.wrapping_add(count), Ordering::Release);
+        count
+    }
+
+    /// Pushes frames whose samples are interleaved, e.g. `[l0, r0, l1, r1]`, and returns the number
+    /// of frames pushed, which is lower than given when the buffer is full.
+    pub fn push_interleaved(&mut self, samples: &[f32]) -> usize {
+        let channels = self.channels();
+        self.push_frames(samples.len() / channels, |frame, channel| {
+            samples[frame * channels + channel]
+        })
+    }
+
+    /// Pushes frames given as one slice per channel, and returns the number of frames pushed.
+    pub fn push_planar(&mut self, channels: &[&[f32]]) -> usize {
+        assert_eq!(channels.len(), self.channels(), "wrong number of channels");
+        let frames = channels.iter().map(|channel| channel.len()).min().unwrap();
+        self.push_frames(frames, |frame, channel| channels[channel][frame])
+    }
+}
+
+/// The reading end of a [`ring_buffer`], usually moved into an audio processor.
+pub struct Consumer {
+    shared: Arc<Shared>,
+}
+
+impl Consumer {
+    pub fn channels(&self) -> usize {
+        self.shared.channels
+    }
+
+    /// The number of frames ready to be popped.
+    pub fn available_frames(&self) -> usize {
+        self.shared.len()
+    }
+
+    pub fn underruns(&self) -> Underruns {
+        self.shared.underruns()
+    }
+
+    /// Pops `frames` frames, passing silence for the missing ones, and returns the number of
+    /// frames popped.
+    fn pop_frames(&mut self, frames: usize, mut store: impl FnMut(usize, usize, f32)) -> usize {
+        let shared = &*self.shared;
+        let read = shared.read.load(Ordering::Relaxed);
+        let count = frames.min(self.available_frames());
+        for frame in 0..frames {
+            for channel in 0..shared.channels {
+                let value = if frame < count {
+                    unsafe { *shared.sample(read.wrapping_add(frame), channel) }
+                } else {
+                    0.0
+                };
+                store(frame, channel, value);
+            }
+        }
+        shared
+            .read
+            .store(read.wrapping_add(count), Ordering::Release);
+        if count < frames {
+            shared.underruns.fetch_add(1, Ordering::Relaxed);
+            shared
+                .underrun_frames
+                .fetch_add((frames - count) as u64, Ordering::Relaxed);
+        }
+        count
+    }
+
+    /// Fills `samples` with interleaved frames.
+    pub fn pop_interleaved(&mut self, samples: &mut [f32]) -> usize {
+        let channels = self.channels();
+        self.pop_frames(samples.len() / channels, |frame, channel, value| {
+            samples[frame * channels + channel] = value
+        })
+    }
+
+    /// Fills one slice per channel.
+    pub fn pop_planar(&mut self, channels: &mut [&mut [f32]]) -> usize {
+        assert_eq!(channels.len(), self.channels(), "wrong number of channels");
+        let frames = channels.iter().map(|channel| channel.len()).min().unwrap();
+        self.pop_frames(frames, |frame, channel, value| {
+            channels[channel][frame] = value
+        })
+    }
+
+    /// Fills an output of an audio worklet node. Channels missing from the buffer are left
+    /// untouched, the ones missing from the output are skipped.
+    pub fn pop_into(&mut self, output: &mut AudioBus) -> usize {
+        let frames = output.samples_per_channel();
+        let channels = output.channel_count();
+        let samples = output.samples_mut();
+        self.pop_frames(frames, |frame, channel, value| {
+            if channel < channels {
+                samples[channel * frames + frame] = value;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer() {
+        let (mut producer, mut consumer) = ring_buffer(2, 4);
+        assert_eq!(
+            producer.push_interleaved(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            3
+        );
+        assert_eq!(producer.push_planar(&[&[7.0, 9.0], &[8.0, 10.0]]), 1);
+        assert_eq!(producer.free_frames(), 0);
+        assert_eq!(consumer.available_frames(), 4);
+
+        let mut left = [0.0; 2];
+        let mut right = [0.0; 2];
+        assert_eq!(consumer.pop_planar(&mut [&mut left, &mut right]), 2);
+        assert_eq!((left, right), ([1.0, 3.0], [2.0, 4.0]));
+
+        // Wraps around the end of the storage.
+        assert_eq!(producer.push_interleaved(&[11.0, 12.0]), 1);
+        let mut samples = [-1.0; 8];
+        assert_eq!(consumer.pop_interleaved(&mut samples), 3);
+        assert_eq!(samples, [5.0, 6.0, 7.0, 8.0, 11.0, 12.0, 0.0, 0.0]);
+        assert_eq!(
+            producer.underruns(),
+            Underruns {
+                count: 1,
+                frames: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_ring_buffer_counter_wrap() {
+        let (mut producer, mut consumer) = ring_buffer(1, 4);
+        producer
+            .shared
+            .read
+            .store(usize::MAX - 1, Ordering::Relaxed);
+        producer
+            .shared
+            .write
+            .store(usize::MAX - 1, Ordering::Relaxed);
+
+        // The counters wrap in the middle of each batch.
+        let mut samples = [0.0; 3];
+        for batch in 0..4 {
+            let start = batch as f32 * 3.0;
+            assert_eq!(
+                producer.push_interleaved(&[start, start + 1.0, start + 2.0]),
+                3
+            );
+            assert_eq!(producer.free_frames(), 1);
+            assert_eq!(consumer.pop_interleaved(&mut samples), 3);
+            assert_eq!(samples, [start, start + 1.0, start + 2.0]);
+        }
+        assert_eq!(consumer.underruns(), Underruns::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn test_ring_buffer_capacity() {
+        ring_buffer(2, 3);
+    }
+}
@@ -0,0 +1,836 @@
+//! Safe wrapper for the Wasm Audio Worklets API, which renders audio with Rust code on the audio
+//! thread of the browser. Needs the `audio_worklet` feature, which links with `-sAUDIO_WORKLET`
+//! and `-sWASM_WORKERS`.
+//!
+//! ```no_run
+//! use emscripten_rs_sys::audio_worklet::*;
+//!
+//! struct Noise(u32);
+//!
+//! impl AudioProcessor for Noise {
+//!     fn process(&mut self, _: &[AudioBus], outputs: &mut [AudioBus], _: &[ParamValues]) -> bool {
+//!         for sample in outputs[0].samples_mut() {
+//!             self.0 = self.0.wrapping_mul(1664525).wrapping_add(1013904223);
+//!             *sample = (self.0 >> 8) as f32 / (1 << 24) as f32 * 0.2 - 0.1;
+//!         }
+//!         true
+//!     }
+//! }
+//!
+//! async fn play() -> Result<AudioWorkletNode, AudioWorkletError> {
+//!     let context = AudioContext::with_options(
+//!         &AudioContextOptions::new().resume_on_user_gesture(true),
+//!     );
+//!     let node = AudioWorkletBuilder::new(c"noise").start(&context, Noise(1)).await?;
+//!     node.connect_to_destination();
+//!     Ok(node)
+//! }
+//! ```
+
+use crate::{
+    workers::{PostTarget, sealed},
+    *,
+};
+use std::{
+    alloc::{Layout, alloc, handle_alloc_error},
+    cell::{RefCell, UnsafeCell},
+    error::Error,
+    ffi::{CStr, CString, c_char, c_int, c_void},
+    fmt,
+    future::Future,
+    rc::{Rc, Weak},
+};
+
+mod js {
+    crate::js! {
+        fn _audio_worklet_rs_on_user_gesture(resume: impl FnMut())
+        {
+            // Without a document, e.g. in Node, no gesture ever comes.
+            if (typeof document == "undefined") {
+                resume.release();
+                return;
+            }
+            // Capturing listeners run even when the page stops the propagation of the event, and
+            // leave the html5 callbacks of the program alone.
+            var events = ["click", "keydown", "touchstart"];
+            var listener = () => {
+                events.forEach((event) => document.removeEventListener(event, listener, true));
+                resume();
+                resume.release();
+            };
+            events.forEach((event) => document.addEventListener(event, listener, { capture: true, once: true }));
+        }
+    }
+}
+
+/// The channels of one input or output of a node for one render quantum, stored one channel
+/// after the other.
+#[repr(transparent)]
+pub struct AudioBus(AudioSampleFrame);
+
+impl AudioBus {
+    pub fn channel_count(&self) -> usize {
+        self.0.numberOfChannels as usize
+    }
+
+    /// The number of samples in each channel, 128 unless the context renders other quantum sizes.
+    pub fn samples_per_channel(&self) -> usize {
+        self.0.samplesPerChannel as usize
+    }
+
+    /// The samples of every channel.
+    pub fn samples(&self) -> &[f32] {
+        let len = self.channel_count() * self.samples_per_channel();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.data, len) }
+    }
+
+    pub fn samples_mut(&mut self) -> &mut [f32] {
+        let len = self.channel_count() * self.samples_per_channel();
+        if len == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.0.data, len) }
+    }
+
+    pub fn channel(&self, index: usize) -> &[f32] {
+        let len = self.samples_per_channel();
+        &self.samples()[index * len..(index + 1) * len]
+    }
+
+    pub fn channel_mut(&mut self, index: usize) -> &mut [f32] {
+        let len = self.samples_per_channel();
+        &mut self.samples_mut()[index * len..(index + 1) * len]
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = &[f32]> {
+        let len = self.samples_per_channel().max(1);
+        self.samples().chunks_exact(len)
+    }
+
+    pub fn channels_mut(&mut self) -> impl Iterator<Item = &mut [f32]> {
+        let len = self.samples_per_channel().max(1);
+        self.samples_mut().chunks_exact_mut(len)
+    }
+}
+
+/// The values of an audio parameter for one render quantum.
+#[repr(transparent)]
+pub struct ParamValues(AudioParamFrame);
+
+impl ParamValues {
+    /// One value per sample, or a single value when the parameter is constant over the quantum.
+    pub fn values(&self) -> &[f32] {
+        if self.0.length <= 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.data, self.0.length as usize) }
+    }
+
+    /// The value of the parameter at `sample`, or 0 when the browser passed no values.
+    pub fn value_at(&self, sample: usize) -> f32 {
+        let values = self.values();
+        match values.last() {
+            Some(last) => *values.get(sample).unwrap_or(last),
+            None => 0.0,
+        }
+    }
+}
+
+/// Renders the audio of a node on the audio worklet thread.
+pub trait AudioProcessor: Send + 'static {
+    /// Renders one quantum into `outputs`, which start out silent. `params` follows the order the
+    /// parameters were added to the [`AudioWorkletBuilder`].
+    ///
+    /// Returns whether the node should keep processing, `false` lets the browser stop calling it
+    /// once it has no inputs.
+    fn process(
+        &mut self,
+        inputs: &[AudioBus],
+        outputs: &mut [AudioBus],
+        params: &[ParamValues],
+    ) -> bool;
+}
+
+/// How often an audio parameter is sampled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutomationRate {
+    /// Once per sample.
+    #[default]
+    ARate = 0,
+    /// Once per render quantum.
+    KRate = 1,
+}
+
+/// An `AudioParam` of the worklet node, exposed to JS in the `parameters` map of the node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamDescriptor {
+    pub default_value: f32,
+    pub min_value: f32,
+    pub max_value: f32,
+    pub automation_rate: AutomationRate,
+}
+
+impl ParamDescriptor {
+    pub fn new(default_value: f32, min_value: f32, max_value: f32) -> Self {
+        Self {
+            default_value,
+            min_value,
+            max_value,
+            automation_rate: AutomationRate::ARate,
+        }
+    }
+
+    pub fn automation_rate(mut self, automation_rate: AutomationRate) -> Self {
+        self.automation_rate = automation_rate;
+        self
+    }
+}
+
+/// An error of the asynchronous setup of an audio worklet node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioWorkletError {
+    /// The audio worklet thread could not be started, e.g. because the program was linked
+    /// without `-sAUDIO_WORKLET`.
+    ThreadFailed,
+    /// The `AudioWorkletProcessor` class could not be registered.
+    ProcessorFailed,
+    /// The setup was dropped before it completed.
+    Cancelled,
+}
+
+impl fmt::Display for AudioWorkletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ThreadFailed => write!(f, "the audio worklet thread could not be started"),
+            Self::ProcessorFailed => write!(f, "the audio worklet processor could not be created"),
+            Self::Cancelled => write!(f, "the audio worklet setup was cancelled"),
+        }
+    }
+}
+
+impl Error for AudioWorkletError {}
+
+/// The processor of a node, owned by the audio worklet thread.
+///
+/// The state is leaked when the node is destroyed, as the browser may still call the process
+/// callback afterwards; only the processor inside it is dropped.
+struct NodeState {
+    processor: UnsafeCell<Option<Box<dyn AudioProcessor>>>,
+}
+
+// The processor is only accessed from the audio worklet thread.
+unsafe impl Sync for NodeState {}
+
+unsafe extern "C" fn process(
+    num_inputs: c_int,
+    inputs: *const AudioSampleFrame,
+    num_outputs: c_int,
+    outputs: *mut AudioSampleFrame,
+    num_params: c_int,
+    params: *const AudioParamFrame,
+    user_data: *mut c_void,
+) -> bool {
+    unsafe fn slice<'a, T, U>(data: *const U, len: c_int) -> &'a [T] {
+        if len <= 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(data as *const T, len as usize) }
+    }
+
+    let state = unsafe { &*(user_data as *const NodeState) };
+    let Some(processor) = (unsafe { &mut *state.processor.get() }) else {
+        return false;
+    };
+    let outputs: &mut [AudioBus] = if num_outputs <= 0 {
+        &mut []
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(outputs as *mut AudioBus, num_outputs as usize) }
+    };
+    let inputs = unsafe { slice(inputs, num_inputs) };
+    let params = unsafe { slice(params, num_params) };
+    processor.process(inputs, outputs, params)
+}
+
+unsafe extern "C" fn drop_processor(state: c_int) {
+    let state = unsafe { &*(state as usize as *const NodeState) };
+    unsafe { *state.processor.get() = None };
+}
+
+/// The state of an audio context, `AudioContext.state` in JS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// Not rendering yet, browsers start contexts suspended until a user gesture.
+    Suspended,
+    Running,
+    Closed,
+    /// Suspended by the system, e.g. during a phone call.
+    Interrupted,
+}
+
+impl State {
+    fn from_raw(state: AUDIO_CONTEXT_STATE) -> Self {
+        match state {
+            1 => Self::Running,
+            2 => Self::Closed,
+            3 => Self::Interrupted,
+            _ => Self::Suspended,
+        }
+    }
+}
+
+/// The `latencyHint` of an audio context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatencyHint {
+    Interactive,
+    Balanced,
+    Playback,
+}
+
+impl LatencyHint {
+    fn as_cstr(self) -> &'static CStr {
+        match self {
+            Self::Interactive => c"interactive",
+            Self::Balanced => c"balanced",
+            Self::Playback => c"playback",
+        }
+    }
+}
+
+/// The options of [`AudioContext::with_options`].
+#[derive(Debug, Clone)]
+pub struct AudioContextOptions {
+    latency_hint: Option<LatencyHint>,
+    sample_rate: Option<u32>,
+    resume_on_user_gesture: bool,
+    worklet_stack_size: usize,
+}
+
+impl Default for AudioContextOptions {
+    fn default() -> Self {
+        Self {
+            latency_hint: None,
+            sample_rate: None,
+            resume_on_user_gesture: false,
+            worklet_stack_size: 64 * 1024,
+        }
+    }
+}
+
+impl AudioContextOptions {
+    /// The smallest stack accepted by [`worklet_stack_size`](Self::worklet_stack_size).
+    pub const MIN_WORKLET_STACK_SIZE: usize = 4 * 1024;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn latency_hint(mut self, latency_hint: LatencyHint) -> Self {
+        self.latency_hint = Some(latency_hint);
+        self
+    }
+
+    /// The sample rate in Hz, the one of the output device by default.
+    pub fn sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Resumes the context on the first click, key press or touch on the page, as browsers only
+    /// allow audio to start from a user gesture.
+    ///
+    /// The gesture is detected with one-time click, keydown and touchstart listeners on the
+    /// document, next to the event callbacks of the program. They are added from JS rather than
+    /// with the html5 callbacks, which hold a single callback per event and would replace the
+    /// ones of the program, so the `audio_worklet` feature enables `em_js`. Without a document,
+    /// e.g. in Node, the option has no effect.
+    pub fn resume_on_user_gesture(mut self, enabled: bool) -> Self {
+        self.resume_on_user_gesture = enabled;
+        self
+    }
+
+    /// The size of the stack of the audio worklet thread, 64 KiB by default. It is rounded up to
+    /// a multiple of 16 bytes, as emscripten requires.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is below [`MIN_WORKLET_STACK_SIZE`](Self::MIN_WORKLET_STACK_SIZE).
+    pub fn worklet_stack_size(mut self, bytes: usize) -> Self {
+        assert!(
+            bytes >= Self::MIN_WORKLET_STACK_SIZE,
+            "the audio worklet stack needs at least {} bytes",
+            Self::MIN_WORKLET_STACK_SIZE
+        );
+        self.worklet_stack_size = bytes.next_multiple_of(16);
+        self
+    }
+}
+
+/// Whether the audio worklet thread of a context runs, it is started by the first node.
+enum WorkletThread {
+    Stopped,
+    /// The setups of the nodes waiting for the thread.
+    Starting(Vec<Setup>),
+    Running,
+    Failed,
+}
+
+struct ContextInner {
+    handle: EMSCRIPTEN_WEBAUDIO_T,
+    worklet_stack_size: usize,
+    worklet: RefCell<WorkletThread>,
+}
+
+impl Drop for ContextInner {
+    fn drop(&mut self) {
+        unsafe { emscripten_destroy_audio_context(self.handle) };
+    }
+}
+
+/// Resumes the context on the first user gesture, unless it was destroyed by then.
+fn resume_on_user_gesture(context: Weak<ContextInner>) {
+    // Resuming has to start while the event is dispatched for the browser to allow it.
+    let resume = move || {
+        if let Some(context) = context.upgrade() {
+            unsafe { emscripten_resume_audio_context_sync(context.handle) };
+        }
+    };
+    unsafe { js::_audio_worklet_rs_on_user_gesture(resume) };
+}
+
+unsafe extern "C" fn resumed(
+    _: EMSCRIPTEN_WEBAUDIO_T,
+    state: AUDIO_CONTEXT_STATE,
+    user_data: *mut c_void,
+) {
+    let sender = unsafe { Box::from_raw(user_data as *mut oneshot::Sender<State>) };
+    sender.send(State::from_raw(state));
+}
+
+/// A Web Audio `AudioContext`, destroyed when the last clone of it is dropped.
+///
+/// The nodes created in the context keep it alive.
+#[derive(Clone)]
+pub struct AudioContext {
+    inner: Rc<ContextInner>,
+}
+
+impl Default for AudioContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioContext {
+    pub fn new() -> Self {
+        Self::with_options(&AudioContextOptions::new())
+    }
+
+    pub fn with_options(options: &AudioContextOptions) -> Self {
+        let attributes = EmscriptenWebAudioCreateAttributes {
+            latencyHint: options
+                .latency_hint
+                .map_or(std::ptr::null(), |hint| hint.as_cstr().as_ptr()),
+            sampleRate: options.sample_rate.unwrap_or(0),
+        };
+        let handle = unsafe { emscripten_create_audio_context(&attributes) };
+        let inner = Rc::new(ContextInner {
+            handle,
+            worklet_stack_size: options.worklet_stack_size,
+            worklet: RefCell::new(WorkletThread::Stopped),
+        });
+        if options.resume_on_user_gesture {
+            resume_on_user_gesture(Rc::downgrade(&inner));
+        }
+        Self { inner }
+    }
+
+    /// The emscripten handle of the context, which JS reads with `emscriptenGetAudioObject`.
+    pub fn handle(&self) -> EMSCRIPTEN_WEBAUDIO_T {
+        self.inner.handle
+    }
+
+    pub fn state(&self) -> State {
+        State::from_raw(unsafe { emscripten_audio_context_state(self.inner.handle) })
+    }
+
+    /// Resumes the context, resolving to its state once the browser allowed or refused it, or to
+    /// [`State::Closed`] if the context was destroyed first.
+    ///
+    /// Browsers only resume contexts from a user gesture, see
+    /// [`AudioContextOptions::resume_on_user_gesture`].
+    pub fn resume(&self) -> impl Future<Output = State> + use<> {
+        let (sender, receiver) = oneshot::channel();
+        unsafe {
+            emscripten_resume_audio_context_async(
+                self.inner.handle,
+                Some(resumed),
+                Box::into_raw(Box::new(sender)) as *mut c_void,
+            )
+        };
+        async move { receiver.await.unwrap_or(State::Closed) }
+    }
+
+    /// Starts resuming the context without waiting for the result.
+    pub fn resume_sync(&self) {
+        unsafe { emscripten_resume_audio_context_sync(self.inner.handle) };
+    }
+
+    /// The audio worklet thread of the context, once a node was started in it.
+    pub fn worklet_thread(&self) -> AudioWorkletThread {
+        AudioWorkletThread(self.inner.handle)
+    }
+
+    /// The number of samples per channel in a render quantum.
+    pub fn quantum_size(&self) -> usize {
+        unsafe { emscripten_audio_context_quantum_size(self.inner.handle) as usize }
+    }
+}
+
+/// The audio worklet thread of a context, or the main thread when posting from the worklet, as a
+/// target of [`post`](crate::workers::post).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioWorkletThread(pub EMSCRIPTEN_WEBAUDIO_T);
+
+impl AudioWorkletThread {
+    pub const MAIN_THREAD: Self = Self(EMSCRIPTEN_AUDIO_MAIN_THREAD as EMSCRIPTEN_WEBAUDIO_T);
+}
+
+impl sealed::Sealed for AudioWorkletThread {}
+
+impl PostTarget for AudioWorkletThread {
+    fn post_id(&self) -> c_int {
+        self.0
+    }
+
+    const POST_VI: unsafe extern "C" fn(c_int, Option<unsafe extern "C" fn(c_int)>, c_int) =
+        emscripten_audio_worklet_post_function_vi;
+
+    const POST_SIG: unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...) =
+        emscripten_audio_worklet_post_function_sig;
+}
+
+/// A handle to an `AudioWorkletNode` whose audio is rendered by an [`AudioProcessor`].
+///
+/// Dropping the handle destroys the node and drops the processor on the audio worklet thread.
+pub struct AudioWorkletNode {
+    context: AudioContext,
+    node: EMSCRIPTEN_AUDIO_WORKLET_NODE_T,
+    state: *const NodeState,
+}
+
+impl AudioWorkletNode {
+    pub fn context(&self) -> &AudioContext {
+        &self.context
+    }
+
+    /// The emscripten handle of the node, which JS reads with `emscriptenGetAudioObject`.
+    pub fn handle(&self) -> EMSCRIPTEN_AUDIO_WORKLET_NODE_T {
+        self.node
+    }
+
+    /// Connects the first output of the node to the speakers.
+    pub fn connect_to_destination(&self) {
+        unsafe { emscripten_audio_node_connect(self.node, self.context.handle(), 0, 0) };
+    }
+
+    /// Connects the output `output` of the node to the input `input` of `destination`.
+    pub fn connect(&self, destination: EMSCRIPTEN_WEBAUDIO_T, output: u32, input: u32) {
+        unsafe {
+            emscripten_audio_node_connect(self.node, destination, output as c_int, input as c_int)
+        };
+    }
+}
+
+impl Drop for AudioWorkletNode {
+    fn drop(&mut self) {
+        unsafe {
+            emscripten_destroy_web_audio_node(self.node);
+            // Posted messages run between two render quanta, never during `process`.
+            emscripten_audio_worklet_post_function_vi(
+                self.context.handle(),
+                Some(drop_processor),
+                self.state as usize as c_int,
+            );
+        }
+    }
+}
+
+/// Sets up an audio worklet node: starts the audio worklet thread of the context if needed,
+/// registers the processor class and creates the node.
+pub struct AudioWorkletBuilder {
+    name: CString,
+    inputs: u32,
+    output_channels: Vec<c_int>,
+    params: Vec<WebAudioParamDescriptor>,
+}
+
+impl AudioWorkletBuilder {
+    /// A node without inputs and a single stereo output, whose processor class is registered
+    /// under `name`.
+    pub fn new(name: &CStr) -> Self {
+        Self {
+            name: name.to_owned(),
+            inputs: 0,
+            output_channels: vec![2],
+            params: Vec::new(),
+        }
+    }
+
+    pub fn inputs(mut self, count: u32) -> Self {
+        self.inputs = count;
+        self
+    }
+
+    /// The channel count of each output.
+    pub fn outputs(mut self, channel_counts: impl IntoIterator<Item = u32>) -> Self {
+        self.output_channels = channel_counts
+            .into_iter()
+            .map(|count| count as c_int)
+            .collect();
+        self
+    }
+
+    pub fn param(mut self, descriptor: ParamDescriptor) -> Self {
+        self.params.push(WebAudioParamDescriptor {
+            defaultValue: descriptor.default_value,
+            minValue: descriptor.min_value,
+            maxValue: descriptor.max_value,
+            automationRate: descriptor.automation_rate as WEBAUDIO_PARAM_AUTOMATION_RATE,
+        });
+        self
+    }
+
+    /// Creates the node rendering with `processor` in `context`.
+    ///
+    /// The setup completes from the JS event loop, so the future must be polled by an executor
+    /// that yields to the browser.
+    pub fn start<P: AudioProcessor>(
+        self,
+        context: &AudioContext,
+        processor: P,
+    ) -> impl Future<Output = Result<AudioWorkletNode, AudioWorkletError>> + use<P> {
+        let (sender, receiver) = oneshot::channel();
+        let setup = Setup {
+            builder: self,
+            context: context.clone(),
+            processor: Box::new(processor),
+            sender,
+        };
+        let mut worklet = context.inner.worklet.borrow_mut();
+        match &mut *worklet {
+            WorkletThread::Stopped => {
+                *worklet = WorkletThread::Starting(vec![setup]);
+                // The stack lives as long as the audio worklet thread, which the browser only
+                // stops when the context is garbage collected.
+                let layout = Layout::from_size_align(context.inner.worklet_stack_size, 16).unwrap();
+                let stack = unsafe { alloc(layout) };
+                if stack.is_null() {
+                    handle_alloc_error(layout);
+                }
+                unsafe {
+                    emscripten_start_wasm_audio_worklet_thread_async(
+                        context.handle(),
+                        stack as *mut c_void,
+                        layout.size() as u32,
+                        Some(thread_started),
+                        Rc::into_raw(context.inner.clone()) as *mut c_void,
+                    )
+                };
+            }
+            WorkletThread::Starting(setups) => setups.push(setup),
+            WorkletThread::Running => create_processor(setup),
+            WorkletThread::Failed => setup.sender.send(Err(AudioWorkletError::ThreadFailed)),
+        }
+        async move { receiver.await.unwrap_or(Err(AudioWorkletError::Cancelled)) }
+    }
+}
+
+struct Setup {
+    builder: AudioWorkletBuilder,
+    context: AudioContext,
+    processor: Box<dyn AudioProcessor>,
+    sender: oneshot::Sender<Result<AudioWorkletNode, AudioWorkletError>>,
+}
+
+unsafe extern "C" fn thread_started(
+    _: EMSCRIPTEN_WEBAUDIO_T,
+    success: bool,
+    user_data: *mut c_void,
+) {
+    let inner = unsafe { Rc::from_raw(user_data as *const ContextInner) };
+    let state = if success {
+        WorkletThread::Running
+    } else {
+        WorkletThread::Failed
+    };
+    let WorkletThread::Starting(setups) = inner.worklet.replace(state) else {
+        unreachable!("the audio worklet thread was started twice");
+    };
+    for setup in setups {
+        if success {
+            create_processor(setup);
+        } else {
+            setup.sender.send(Err(AudioWorkletError::ThreadFailed));
+        }
+    }
+}
+
+fn create_processor(setup: Setup) {
+    // The callback owns the setup until the processor is registered.
+    let setup = Box::new(setup);
+    let options = WebAudioWorkletProcessorCreateOptions {
+        name: setup.builder.name.as_ptr(),
+        numAudioParams: setup.builder.params.len() as c_int,
+        audioParamDescriptors: setup.builder.params.as_ptr(),
+    };
+    unsafe {
+        emscripten_create_wasm_audio_worklet_processor_async(
+            setup.context.handle(),
+            &options,
+            Some(processor_created),
+            Box::into_raw(setup) as *mut c_void,
+        )
+    };
+}
+
+unsafe extern "C" fn processor_created(
+    context: EMSCRIPTEN_WEBAUDIO_T,
+    success: bool,
+    user_data: *mut c_void,
+) {
+    let mut setup = unsafe { Box::from_raw(user_data as *mut Setup) };
+    if !success {
+        setup.sender.send(Err(AudioWorkletError::ProcessorFailed));
+        return;
+    }
+    let options = EmscriptenAudioWorkletNodeCreateOptions {
+        numberOfInputs: setup.builder.inputs as c_int,
+        numberOfOutputs: setup.builder.output_channels.len() as c_int,
+        outputChannelCounts: setup.builder.output_channels.as_mut_ptr(),
+    };
+    let state: &'static NodeState = Box::leak(Box::new(NodeState {
+        processor: UnsafeCell::new(Some(setup.processor)),
+    }));
+    let node = unsafe {
+        emscripten_create_wasm_audio_worklet_node(
+            context,
+            setup.builder.name.as_ptr(),
+            &options,
+            Some(process),
+            state as *const NodeState as *mut c_void,
+        )
+    };
+    setup.sender.send(Ok(AudioWorkletNode {
+        context: setup.context,
+        node,
+        state,
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Gain;
+
+    impl AudioProcessor for Gain {
+        fn process(
+            &mut self,
+            inputs: &[AudioBus],
+            outputs: &mut [AudioBus],
+            params: &[ParamValues],
+        ) -> bool {
+            for (i, (input, output)) in inputs[0]
+                .channels()
+                .zip(outputs[0].channels_mut())
+                .enumerate()
+            {
+                for (j, (input, output)) in input.iter().zip(output).enumerate() {
+                    *output = input * params[0].value_at(i * 4 + j);
+                }
+            }
+            true
+        }
+    }
+
+    #[test]
+    fn test_process() {
+        let mut input = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let mut output = [0.0; 8];
+        let mut gain = [0.5];
+        let inputs = [AudioSampleFrame {
+            numberOfChannels: 2,
+            samplesPerChannel: 4,
+            data: input.as_mut_ptr(),
+        }];
+        let mut outputs = [AudioSampleFrame {
+            numberOfChannels: 2,
+            samplesPerChannel: 4,
+            data: output.as_mut_ptr(),
+        }];
+        let params = [AudioParamFrame {
+            length: 1,
+            data: gain.as_mut_ptr(),
+        }];
+        let state = NodeState {
+            processor: UnsafeCell::new(Some(Box::new(Gain))),
+        };
+        let state = &state as *const NodeState as *mut c_void;
+        unsafe {
+            assert!(process(
+                1,
+                inputs.as_ptr(),
+                1,
+                outputs.as_mut_ptr(),
+                1,
+                params.as_ptr(),
+                state
+            ));
+            assert_eq!(output, [0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0]);
+            drop_processor(state as usize as c_int);
+            assert!(!process(
+                1,
+                inputs.as_ptr(),
+                1,
+                outputs.as_mut_ptr(),
+                1,
+                params.as_ptr(),
+                state
+            ));
+        }
+    }
+
+    #[test]
+    fn test_state() {
+        assert_eq!(State::from_raw(0), State::Suspended);
+        assert_eq!(State::from_raw(1), State::Running);
+        assert_eq!(State::from_raw(2), State::Closed);
+        assert_eq!(State::from_raw(3), State::Interrupted);
+        assert_eq!(LatencyHint::Playback.as_cstr(), c"playback");
+    }
+
+    #[test]
+    fn test_value_at() {
+        let mut values = [0.25, 0.5];
+        let param = |length: c_int, data: *mut f32| ParamValues(AudioParamFrame { length, data });
+        assert_eq!(param(2, values.as_mut_ptr()).value_at(1), 0.5);
+        assert_eq!(param(2, values.as_mut_ptr()).value_at(7), 0.5);
+        assert_eq!(param(1, values.as_mut_ptr()).value_at(7), 0.25);
+        assert_eq!(param(0, std::ptr::null_mut()).value_at(0), 0.0);
+    }
+
+    #[test]
+    fn test_worklet_stack_size() {
+        let options = AudioContextOptions::new().worklet_stack_size(5000);
+        assert_eq!(options.worklet_stack_size, 5008);
+    }
+
+    #[test]
+    #[should_panic(expected = "the audio worklet stack needs at least")]
+    fn test_worklet_stack_size_too_small() {
+        AudioContextOptions::new().worklet_stack_size(64);
+    }
+}
@@ -0,0 +1,33 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_NOTIFY_ALL_WAITERS: i32 = -1;
+pub type emscripten_async_wait_callback_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        address: *mut i32,
+        value: u32,
+        waitResult: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+unsafe extern "C" {
+    pub fn emscripten_atomic_wait_async(
+        addr: *mut ::std::os::raw::c_void,
+        value: u32,
+        asyncWaitFinished: emscripten_async_wait_callback_t,
+        userData: *mut ::std::os::raw::c_void,
+        maxWaitMilliseconds: f64,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn emscripten_atomic_cancel_wait_async(waitToken: i32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_atomic_cancel_all_wait_asyncs() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_atomic_cancel_all_wait_asyncs_at_address(
+        addr: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
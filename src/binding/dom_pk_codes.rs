@@ -0,0 +1,14 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_compute_dom_pk_code(
+        keyCodeString: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_dom_pk_code_to_string(
+        code: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
@@ -0,0 +1,180 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_asm_const_int(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_ptr(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_double(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_int_sync_on_main_thread(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_ptr_sync_on_main_thread(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_double_sync_on_main_thread(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_asm_const_async_on_main_thread(
+        code: *const ::std::os::raw::c_char,
+        arg_sigs: *const ::std::os::raw::c_char,
+        ...
+    );
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct __em_asm_sig {
+    pub _address: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_float_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_float_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_double_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_double_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_char_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_char_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_signed_char_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_signed_char_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_unsigned_char_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_unsigned_char_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_short_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_short_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_unsigned_short_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_unsigned_short_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_int_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_int_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_unsigned_int_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_unsigned_int_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_long_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_long_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_unsigned_long_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_unsigned_long_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_bool__void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_bool__void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_wchar_t_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_wchar_t_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_long_long_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_long_long_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_unsigned_long_long_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_unsigned_long_long_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct __em_asm_type_tuple {
+    pub _address: u8,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct __em_asm_sig_builder {
+    pub _address: u8,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of template specialization: __em_asm_sig_open0_int_void_close0"]
+        [::std::mem::size_of::<__em_asm_sig>() - 1usize];
+    ["Align of template specialization: __em_asm_sig_open0_int_void_close0"]
+        [::std::mem::align_of::<__em_asm_sig>() - 1usize];
+};
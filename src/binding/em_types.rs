@@ -0,0 +1,20 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub type emscripten_align1_short = ::std::os::raw::c_short;
+pub type emscripten_align4_int64 = ::std::os::raw::c_longlong;
+pub type emscripten_align2_int64 = ::std::os::raw::c_longlong;
+pub type emscripten_align1_int64 = ::std::os::raw::c_longlong;
+pub type emscripten_align2_int = ::std::os::raw::c_int;
+pub type emscripten_align1_int = ::std::os::raw::c_int;
+pub type emscripten_align2_float = f32;
+pub type emscripten_align1_float = f32;
+pub type emscripten_align4_double = f64;
+pub type emscripten_align2_double = f64;
+pub type emscripten_align1_double = f64;
+pub type em_callback_func = ::std::option::Option<unsafe extern "C" fn()>;
+pub type em_arg_callback_func =
+    ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type em_str_callback_func =
+    ::std::option::Option<unsafe extern "C" fn(arg1: *const ::std::os::raw::c_char)>;
@@ -0,0 +1,62 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_unwind_to_js_event_loop() -> !;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_timeout(
+        cb: ::std::option::Option<unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void)>,
+        msecs: f64,
+        user_data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_clear_timeout(id: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_set_timeout_loop(
+        cb: ::std::option::Option<
+            unsafe extern "C" fn(time: f64, user_data: *mut ::std::os::raw::c_void) -> bool,
+        >,
+        interval_ms: f64,
+        user_data: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_set_immediate(
+        cb: ::std::option::Option<unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void)>,
+        user_data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_clear_immediate(id: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_set_immediate_loop(
+        cb: ::std::option::Option<
+            unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void) -> bool,
+        >,
+        user_data: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_set_interval(
+        cb: ::std::option::Option<unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void)>,
+        interval_ms: f64,
+        user_data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_clear_interval(id: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_runtime_keepalive_push();
+}
+unsafe extern "C" {
+    pub fn emscripten_runtime_keepalive_pop();
+}
+unsafe extern "C" {
+    pub fn emscripten_runtime_keepalive_check() -> bool;
+}
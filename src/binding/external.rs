@@ -0,0 +1,196 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const _LIBCPP_AVAILABILITY_HAS_FILESYSTEM_LIBRARY: u32 = 1;
+pub const SEM_VALUE_MAX: u32 = 2147483647;
+pub const SEM_NSEMS_MAX: u32 = 256;
+pub const _POSIX_SEM_NSEMS_MAX: u32 = 256;
+pub const _POSIX_SEM_VALUE_MAX: u32 = 32767;
+pub type va_list = __builtin_va_list;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_FILE {
+    _unused: [u8; 0],
+}
+pub type FILE = _IO_FILE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct pthread_attr_t {
+    pub __u: pthread_attr_t__bindgen_ty_1,
+    pub _a_transferredcanvases: *const ::std::os::raw::c_char,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_attr_t__bindgen_ty_1 {
+    pub __i: [::std::os::raw::c_int; 10usize],
+    pub __vi: [::std::os::raw::c_int; 10usize],
+    pub __s: [::std::os::raw::c_ulong; 10usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_attr_t__bindgen_ty_1"]
+        [::std::mem::size_of::<pthread_attr_t__bindgen_ty_1>() - 40usize];
+    ["Alignment of pthread_attr_t__bindgen_ty_1"]
+        [::std::mem::align_of::<pthread_attr_t__bindgen_ty_1>() - 4usize];
+    ["Offset of field: pthread_attr_t__bindgen_ty_1::__i"]
+        [::std::mem::offset_of!(pthread_attr_t__bindgen_ty_1, __i) - 0usize];
+    ["Offset of field: pthread_attr_t__bindgen_ty_1::__vi"]
+        [::std::mem::offset_of!(pthread_attr_t__bindgen_ty_1, __vi) - 0usize];
+    ["Offset of field: pthread_attr_t__bindgen_ty_1::__s"]
+        [::std::mem::offset_of!(pthread_attr_t__bindgen_ty_1, __s) - 0usize];
+};
+impl Default for pthread_attr_t__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_attr_t"][::std::mem::size_of::<pthread_attr_t>() - 44usize];
+    ["Alignment of pthread_attr_t"][::std::mem::align_of::<pthread_attr_t>() - 4usize];
+    ["Offset of field: pthread_attr_t::__u"][::std::mem::offset_of!(pthread_attr_t, __u) - 0usize];
+    ["Offset of field: pthread_attr_t::_a_transferredcanvases"]
+        [::std::mem::offset_of!(pthread_attr_t, _a_transferredcanvases) - 40usize];
+};
+impl Default for pthread_attr_t {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type pthread_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBindGroup = *mut WGPUBindGroupImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBindGroupLayoutImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBindGroupLayout = *mut WGPUBindGroupLayoutImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUBufferImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUBuffer = *mut WGPUBufferImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandBufferImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUCommandBuffer = *mut WGPUCommandBufferImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUCommandEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUCommandEncoder = *mut WGPUCommandEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePassEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUComputePassEncoder = *mut WGPUComputePassEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUComputePipelineImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUComputePipeline = *mut WGPUComputePipelineImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUDeviceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUDevice = *mut WGPUDeviceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUPipelineLayoutImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUPipelineLayout = *mut WGPUPipelineLayoutImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUQuerySetImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUQuerySet = *mut WGPUQuerySetImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUQueueImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUQueue = *mut WGPUQueueImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderBundle = *mut WGPURenderBundleImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderBundleEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderBundleEncoder = *mut WGPURenderBundleEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPassEncoderImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderPassEncoder = *mut WGPURenderPassEncoderImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPURenderPipelineImpl {
+    _unused: [u8; 0],
+}
+pub type WGPURenderPipeline = *mut WGPURenderPipelineImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSamplerImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSampler = *mut WGPUSamplerImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUShaderModuleImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUShaderModule = *mut WGPUShaderModuleImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSurfaceImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSurface = *mut WGPUSurfaceImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUSwapChainImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUSwapChain = *mut WGPUSwapChainImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUTexture = *mut WGPUTextureImpl;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WGPUTextureViewImpl {
+    _unused: [u8; 0],
+}
+pub type WGPUTextureView = *mut WGPUTextureViewImpl;
+pub type __builtin_va_list = *mut ::std::os::raw::c_void;
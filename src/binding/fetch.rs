@@ -0,0 +1,172 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_FETCH_LOAD_TO_MEMORY: u32 = 1;
+pub const EMSCRIPTEN_FETCH_STREAM_DATA: u32 = 2;
+pub const EMSCRIPTEN_FETCH_PERSIST_FILE: u32 = 4;
+pub const EMSCRIPTEN_FETCH_APPEND: u32 = 8;
+pub const EMSCRIPTEN_FETCH_REPLACE: u32 = 16;
+pub const EMSCRIPTEN_FETCH_NO_DOWNLOAD: u32 = 32;
+pub const EMSCRIPTEN_FETCH_SYNCHRONOUS: u32 = 64;
+pub const EMSCRIPTEN_FETCH_WAITABLE: u32 = 128;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten_fetch_attr_t {
+    pub requestMethod: [::std::os::raw::c_char; 32usize],
+    pub userData: *mut ::std::os::raw::c_void,
+    pub onsuccess: ::std::option::Option<unsafe extern "C" fn(fetch: *mut emscripten_fetch_t)>,
+    pub onerror: ::std::option::Option<unsafe extern "C" fn(fetch: *mut emscripten_fetch_t)>,
+    pub onprogress: ::std::option::Option<unsafe extern "C" fn(fetch: *mut emscripten_fetch_t)>,
+    pub onreadystatechange:
+        ::std::option::Option<unsafe extern "C" fn(fetch: *mut emscripten_fetch_t)>,
+    pub attributes: u32,
+    pub timeoutMSecs: u32,
+    pub withCredentials: bool,
+    pub destinationPath: *const ::std::os::raw::c_char,
+    pub userName: *const ::std::os::raw::c_char,
+    pub password: *const ::std::os::raw::c_char,
+    pub requestHeaders: *const *const ::std::os::raw::c_char,
+    pub overriddenMimeType: *const ::std::os::raw::c_char,
+    pub requestData: *const ::std::os::raw::c_char,
+    pub requestDataSize: usize,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of emscripten_fetch_attr_t"][::std::mem::size_of::<emscripten_fetch_attr_t>() - 92usize];
+    ["Alignment of emscripten_fetch_attr_t"]
+        [::std::mem::align_of::<emscripten_fetch_attr_t>() - 4usize];
+    ["Offset of field: emscripten_fetch_attr_t::requestMethod"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, requestMethod) - 0usize];
+    ["Offset of field: emscripten_fetch_attr_t::userData"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, userData) - 32usize];
+    ["Offset of field: emscripten_fetch_attr_t::onsuccess"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, onsuccess) - 36usize];
+    ["Offset of field: emscripten_fetch_attr_t::onerror"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, onerror) - 40usize];
+    ["Offset of field: emscripten_fetch_attr_t::onprogress"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, onprogress) - 44usize];
+    ["Offset of field: emscripten_fetch_attr_t::onreadystatechange"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, onreadystatechange) - 48usize];
+    ["Offset of field: emscripten_fetch_attr_t::attributes"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, attributes) - 52usize];
+    ["Offset of field: emscripten_fetch_attr_t::timeoutMSecs"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, timeoutMSecs) - 56usize];
+    ["Offset of field: emscripten_fetch_attr_t::withCredentials"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, withCredentials) - 60usize];
+    ["Offset of field: emscripten_fetch_attr_t::destinationPath"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, destinationPath) - 64usize];
+    ["Offset of field: emscripten_fetch_attr_t::userName"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, userName) - 68usize];
+    ["Offset of field: emscripten_fetch_attr_t::password"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, password) - 72usize];
+    ["Offset of field: emscripten_fetch_attr_t::requestHeaders"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, requestHeaders) - 76usize];
+    ["Offset of field: emscripten_fetch_attr_t::overriddenMimeType"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, overriddenMimeType) - 80usize];
+    ["Offset of field: emscripten_fetch_attr_t::requestData"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, requestData) - 84usize];
+    ["Offset of field: emscripten_fetch_attr_t::requestDataSize"]
+        [::std::mem::offset_of!(emscripten_fetch_attr_t, requestDataSize) - 88usize];
+};
+impl Default for emscripten_fetch_attr_t {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten_fetch_t {
+    pub id: u32,
+    pub userData: *mut ::std::os::raw::c_void,
+    pub url: *const ::std::os::raw::c_char,
+    pub data: *const ::std::os::raw::c_char,
+    pub numBytes: u64,
+    pub dataOffset: u64,
+    pub totalBytes: u64,
+    pub readyState: ::std::os::raw::c_ushort,
+    pub status: ::std::os::raw::c_ushort,
+    pub statusText: [::std::os::raw::c_char; 64usize],
+    pub __attributes: emscripten_fetch_attr_t,
+    pub responseUrl: *const ::std::os::raw::c_char,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of emscripten_fetch_t"][::std::mem::size_of::<emscripten_fetch_t>() - 208usize];
+    ["Alignment of emscripten_fetch_t"][::std::mem::align_of::<emscripten_fetch_t>() - 8usize];
+    ["Offset of field: emscripten_fetch_t::id"]
+        [::std::mem::offset_of!(emscripten_fetch_t, id) - 0usize];
+    ["Offset of field: emscripten_fetch_t::userData"]
+        [::std::mem::offset_of!(emscripten_fetch_t, userData) - 4usize];
+    ["Offset of field: emscripten_fetch_t::url"]
+        [::std::mem::offset_of!(emscripten_fetch_t, url) - 8usize];
+    ["Offset of field: emscripten_fetch_t::data"]
+        [::std::mem::offset_of!(emscripten_fetch_t, data) - 12usize];
+    ["Offset of field: emscripten_fetch_t::numBytes"]
+        [::std::mem::offset_of!(emscripten_fetch_t, numBytes) - 16usize];
+    ["Offset of field: emscripten_fetch_t::dataOffset"]
+        [::std::mem::offset_of!(emscripten_fetch_t, dataOffset) - 24usize];
+    ["Offset of field: emscripten_fetch_t::totalBytes"]
+        [::std::mem::offset_of!(emscripten_fetch_t, totalBytes) - 32usize];
+    ["Offset of field: emscripten_fetch_t::readyState"]
+        [::std::mem::offset_of!(emscripten_fetch_t, readyState) - 40usize];
+    ["Offset of field: emscripten_fetch_t::status"]
+        [::std::mem::offset_of!(emscripten_fetch_t, status) - 42usize];
+    ["Offset of field: emscripten_fetch_t::statusText"]
+        [::std::mem::offset_of!(emscripten_fetch_t, statusText) - 44usize];
+    ["Offset of field: emscripten_fetch_t::__attributes"]
+        [::std::mem::offset_of!(emscripten_fetch_t, __attributes) - 108usize];
+    ["Offset of field: emscripten_fetch_t::responseUrl"]
+        [::std::mem::offset_of!(emscripten_fetch_t, responseUrl) - 200usize];
+};
+impl Default for emscripten_fetch_t {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_attr_init(fetch_attr: *mut emscripten_fetch_attr_t);
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch(
+        fetch_attr: *mut emscripten_fetch_attr_t,
+        url: *const ::std::os::raw::c_char,
+    ) -> *mut emscripten_fetch_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_wait(
+        fetch: *mut emscripten_fetch_t,
+        timeoutMSecs: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_close(fetch: *mut emscripten_fetch_t) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_get_response_headers_length(fetch: *mut emscripten_fetch_t) -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_get_response_headers(
+        fetch: *mut emscripten_fetch_t,
+        dst: *mut ::std::os::raw::c_char,
+        dstSizeBytes: usize,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_unpack_response_headers(
+        headersString: *const ::std::os::raw::c_char,
+    ) -> *mut *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_fetch_free_unpacked_response_headers(
+        unpackedHeaders: *mut *mut ::std::os::raw::c_char,
+    );
+}
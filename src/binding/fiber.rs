@@ -0,0 +1,100 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct asyncify_data_s {
+    pub stack_ptr: *mut ::std::os::raw::c_void,
+    #[doc = " Current position in the Asyncify stack (*not* the C stack)"]
+    pub stack_limit: *mut ::std::os::raw::c_void,
+    #[doc = " Where the Asyncify stack ends."]
+    pub rewind_id: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of asyncify_data_s"][::std::mem::size_of::<asyncify_data_s>() - 12usize];
+    ["Alignment of asyncify_data_s"][::std::mem::align_of::<asyncify_data_s>() - 4usize];
+    ["Offset of field: asyncify_data_s::stack_ptr"]
+        [::std::mem::offset_of!(asyncify_data_s, stack_ptr) - 0usize];
+    ["Offset of field: asyncify_data_s::stack_limit"]
+        [::std::mem::offset_of!(asyncify_data_s, stack_limit) - 4usize];
+    ["Offset of field: asyncify_data_s::rewind_id"]
+        [::std::mem::offset_of!(asyncify_data_s, rewind_id) - 8usize];
+};
+impl Default for asyncify_data_s {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type asyncify_data_t = asyncify_data_s;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten_fiber_s {
+    pub stack_base: *mut ::std::os::raw::c_void,
+    #[doc = " Where the C stack starts (NOTE: grows down)."]
+    pub stack_limit: *mut ::std::os::raw::c_void,
+    #[doc = " Where the C stack ends."]
+    pub stack_ptr: *mut ::std::os::raw::c_void,
+    #[doc = " Current position in the C stack."]
+    pub entry: em_arg_callback_func,
+    #[doc = " Function to call when resuming this context. If NULL, asyncify_data is used to rewind the call stack."]
+    pub user_data: *mut ::std::os::raw::c_void,
+    #[doc = " Opaque pointer, passed as-is to the entry function."]
+    pub asyncify_data: asyncify_data_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of emscripten_fiber_s"][::std::mem::size_of::<emscripten_fiber_s>() - 32usize];
+    ["Alignment of emscripten_fiber_s"][::std::mem::align_of::<emscripten_fiber_s>() - 4usize];
+    ["Offset of field: emscripten_fiber_s::stack_base"]
+        [::std::mem::offset_of!(emscripten_fiber_s, stack_base) - 0usize];
+    ["Offset of field: emscripten_fiber_s::stack_limit"]
+        [::std::mem::offset_of!(emscripten_fiber_s, stack_limit) - 4usize];
+    ["Offset of field: emscripten_fiber_s::stack_ptr"]
+        [::std::mem::offset_of!(emscripten_fiber_s, stack_ptr) - 8usize];
+    ["Offset of field: emscripten_fiber_s::entry"]
+        [::std::mem::offset_of!(emscripten_fiber_s, entry) - 12usize];
+    ["Offset of field: emscripten_fiber_s::user_data"]
+        [::std::mem::offset_of!(emscripten_fiber_s, user_data) - 16usize];
+    ["Offset of field: emscripten_fiber_s::asyncify_data"]
+        [::std::mem::offset_of!(emscripten_fiber_s, asyncify_data) - 20usize];
+};
+impl Default for emscripten_fiber_s {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type emscripten_fiber_t = emscripten_fiber_s;
+unsafe extern "C" {
+    pub fn emscripten_fiber_init(
+        fiber: *mut emscripten_fiber_t,
+        entry_func: em_arg_callback_func,
+        entry_func_arg: *mut ::std::os::raw::c_void,
+        c_stack: *mut ::std::os::raw::c_void,
+        c_stack_size: usize,
+        asyncify_stack: *mut ::std::os::raw::c_void,
+        asyncify_stack_size: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_fiber_init_from_current_context(
+        fiber: *mut emscripten_fiber_t,
+        asyncify_stack: *mut ::std::os::raw::c_void,
+        asyncify_stack_size: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_fiber_swap(
+        old_fiber: *mut emscripten_fiber_t,
+        new_fiber: *mut emscripten_fiber_t,
+    );
+}
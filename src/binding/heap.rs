@@ -0,0 +1,38 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_PAGE_SIZE: u32 = 65536;
+unsafe extern "C" {
+    pub fn emscripten_get_sbrk_ptr() -> *mut usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_resize_heap(requested_size: usize) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_heap_size() -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_heap_max() -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_builtin_memalign(
+        alignment: usize,
+        size: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_builtin_malloc(size: usize) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_builtin_realloc(
+        ptr: *mut ::std::os::raw::c_void,
+        size: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_builtin_calloc(nmemb: usize, size: usize) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_builtin_free(ptr: *mut ::std::os::raw::c_void);
+}
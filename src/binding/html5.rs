@@ -0,0 +1,1228 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_RESULT_SUCCESS: u32 = 0;
+pub const EMSCRIPTEN_RESULT_DEFERRED: u32 = 1;
+pub const EMSCRIPTEN_RESULT_NOT_SUPPORTED: i32 = -1;
+pub const EMSCRIPTEN_RESULT_FAILED_NOT_DEFERRED: i32 = -2;
+pub const EMSCRIPTEN_RESULT_INVALID_TARGET: i32 = -3;
+pub const EMSCRIPTEN_RESULT_UNKNOWN_TARGET: i32 = -4;
+pub const EMSCRIPTEN_RESULT_INVALID_PARAM: i32 = -5;
+pub const EMSCRIPTEN_RESULT_FAILED: i32 = -6;
+pub const EMSCRIPTEN_RESULT_NO_DATA: i32 = -7;
+pub const EMSCRIPTEN_RESULT_TIMED_OUT: i32 = -8;
+pub const EMSCRIPTEN_EVENT_KEYPRESS: u32 = 1;
+pub const EMSCRIPTEN_EVENT_KEYDOWN: u32 = 2;
+pub const EMSCRIPTEN_EVENT_KEYUP: u32 = 3;
+pub const EMSCRIPTEN_EVENT_CLICK: u32 = 4;
+pub const EMSCRIPTEN_EVENT_MOUSEDOWN: u32 = 5;
+pub const EMSCRIPTEN_EVENT_MOUSEUP: u32 = 6;
+pub const EMSCRIPTEN_EVENT_DBLCLICK: u32 = 7;
+pub const EMSCRIPTEN_EVENT_MOUSEMOVE: u32 = 8;
+pub const EMSCRIPTEN_EVENT_WHEEL: u32 = 9;
+pub const EMSCRIPTEN_EVENT_RESIZE: u32 = 10;
+pub const EMSCRIPTEN_EVENT_SCROLL: u32 = 11;
+pub const EMSCRIPTEN_EVENT_BLUR: u32 = 12;
+pub const EMSCRIPTEN_EVENT_FOCUS: u32 = 13;
+pub const EMSCRIPTEN_EVENT_FOCUSIN: u32 = 14;
+pub const EMSCRIPTEN_EVENT_FOCUSOUT: u32 = 15;
+pub const EMSCRIPTEN_EVENT_DEVICEORIENTATION: u32 = 16;
+pub const EMSCRIPTEN_EVENT_DEVICEMOTION: u32 = 17;
+pub const EMSCRIPTEN_EVENT_ORIENTATIONCHANGE: u32 = 18;
+pub const EMSCRIPTEN_EVENT_FULLSCREENCHANGE: u32 = 19;
+pub const EMSCRIPTEN_EVENT_POINTERLOCKCHANGE: u32 = 20;
+pub const EMSCRIPTEN_EVENT_VISIBILITYCHANGE: u32 = 21;
+pub const EMSCRIPTEN_EVENT_TOUCHSTART: u32 = 22;
+pub const EMSCRIPTEN_EVENT_TOUCHEND: u32 = 23;
+pub const EMSCRIPTEN_EVENT_TOUCHMOVE: u32 = 24;
+pub const EMSCRIPTEN_EVENT_TOUCHCANCEL: u32 = 25;
+pub const EMSCRIPTEN_EVENT_GAMEPADCONNECTED: u32 = 26;
+pub const EMSCRIPTEN_EVENT_GAMEPADDISCONNECTED: u32 = 27;
+pub const EMSCRIPTEN_EVENT_BEFOREUNLOAD: u32 = 28;
+pub const EMSCRIPTEN_EVENT_BATTERYCHARGINGCHANGE: u32 = 29;
+pub const EMSCRIPTEN_EVENT_BATTERYLEVELCHANGE: u32 = 30;
+pub const EMSCRIPTEN_EVENT_WEBGLCONTEXTLOST: u32 = 31;
+pub const EMSCRIPTEN_EVENT_WEBGLCONTEXTRESTORED: u32 = 32;
+pub const EMSCRIPTEN_EVENT_MOUSEENTER: u32 = 33;
+pub const EMSCRIPTEN_EVENT_MOUSELEAVE: u32 = 34;
+pub const EMSCRIPTEN_EVENT_MOUSEOVER: u32 = 35;
+pub const EMSCRIPTEN_EVENT_MOUSEOUT: u32 = 36;
+pub const EMSCRIPTEN_EVENT_CANVASRESIZED: u32 = 37;
+pub const EMSCRIPTEN_EVENT_POINTERLOCKERROR: u32 = 38;
+pub const EMSCRIPTEN_EVENT_TARGET_INVALID: u32 = 0;
+pub const EM_HTML5_SHORT_STRING_LEN_BYTES: u32 = 32;
+pub const EM_HTML5_MEDIUM_STRING_LEN_BYTES: u32 = 64;
+pub const EM_HTML5_LONG_STRING_LEN_BYTES: u32 = 128;
+pub const EMSCRIPTEN_DEVICE_MOTION_EVENT_SUPPORTS_ACCELERATION: u32 = 1;
+pub const EMSCRIPTEN_DEVICE_MOTION_EVENT_SUPPORTS_ACCELERATION_INCLUDING_GRAVITY: u32 = 2;
+pub const EMSCRIPTEN_DEVICE_MOTION_EVENT_SUPPORTS_ROTATION_RATE: u32 = 4;
+pub const EMSCRIPTEN_ORIENTATION_UNSUPPORTED: u32 = 0;
+pub const EMSCRIPTEN_ORIENTATION_PORTRAIT_PRIMARY: u32 = 1;
+pub const EMSCRIPTEN_ORIENTATION_PORTRAIT_SECONDARY: u32 = 2;
+pub const EMSCRIPTEN_ORIENTATION_LANDSCAPE_PRIMARY: u32 = 4;
+pub const EMSCRIPTEN_ORIENTATION_LANDSCAPE_SECONDARY: u32 = 8;
+pub const EMSCRIPTEN_FULLSCREEN_SCALE_DEFAULT: u32 = 0;
+pub const EMSCRIPTEN_FULLSCREEN_SCALE_STRETCH: u32 = 1;
+pub const EMSCRIPTEN_FULLSCREEN_SCALE_ASPECT: u32 = 2;
+pub const EMSCRIPTEN_FULLSCREEN_SCALE_CENTER: u32 = 3;
+pub const EMSCRIPTEN_FULLSCREEN_CANVAS_SCALE_NONE: u32 = 0;
+pub const EMSCRIPTEN_FULLSCREEN_CANVAS_SCALE_STDDEF: u32 = 1;
+pub const EMSCRIPTEN_FULLSCREEN_CANVAS_SCALE_HIDEF: u32 = 2;
+pub const EMSCRIPTEN_FULLSCREEN_FILTERING_DEFAULT: u32 = 0;
+pub const EMSCRIPTEN_FULLSCREEN_FILTERING_NEAREST: u32 = 1;
+pub const EMSCRIPTEN_FULLSCREEN_FILTERING_BILINEAR: u32 = 2;
+pub const EMSCRIPTEN_VISIBILITY_HIDDEN: u32 = 0;
+pub const EMSCRIPTEN_VISIBILITY_VISIBLE: u32 = 1;
+pub const EMSCRIPTEN_VISIBILITY_PRERENDER: u32 = 2;
+pub const EMSCRIPTEN_VISIBILITY_UNLOADED: u32 = 3;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenKeyboardEvent {
+    pub timestamp: f64,
+    pub location: ::std::os::raw::c_uint,
+    pub ctrlKey: bool,
+    pub shiftKey: bool,
+    pub altKey: bool,
+    pub metaKey: bool,
+    pub repeat: bool,
+    pub charCode: ::std::os::raw::c_uint,
+    pub keyCode: ::std::os::raw::c_uint,
+    pub which: ::std::os::raw::c_uint,
+    pub key: [::std::os::raw::c_char; 32usize],
+    pub code: [::std::os::raw::c_char; 32usize],
+    pub charValue: [::std::os::raw::c_char; 32usize],
+    pub locale: [::std::os::raw::c_char; 32usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenKeyboardEvent"]
+        [::std::mem::size_of::<EmscriptenKeyboardEvent>() - 160usize];
+    ["Alignment of EmscriptenKeyboardEvent"]
+        [::std::mem::align_of::<EmscriptenKeyboardEvent>() - 8usize];
+    ["Offset of field: EmscriptenKeyboardEvent::timestamp"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, timestamp) - 0usize];
+    ["Offset of field: EmscriptenKeyboardEvent::location"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, location) - 8usize];
+    ["Offset of field: EmscriptenKeyboardEvent::ctrlKey"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, ctrlKey) - 12usize];
+    ["Offset of field: EmscriptenKeyboardEvent::shiftKey"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, shiftKey) - 13usize];
+    ["Offset of field: EmscriptenKeyboardEvent::altKey"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, altKey) - 14usize];
+    ["Offset of field: EmscriptenKeyboardEvent::metaKey"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, metaKey) - 15usize];
+    ["Offset of field: EmscriptenKeyboardEvent::repeat"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, repeat) - 16usize];
+    ["Offset of field: EmscriptenKeyboardEvent::charCode"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, charCode) - 20usize];
+    ["Offset of field: EmscriptenKeyboardEvent::keyCode"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, keyCode) - 24usize];
+    ["Offset of field: EmscriptenKeyboardEvent::which"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, which) - 28usize];
+    ["Offset of field: EmscriptenKeyboardEvent::key"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, key) - 32usize];
+    ["Offset of field: EmscriptenKeyboardEvent::code"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, code) - 64usize];
+    ["Offset of field: EmscriptenKeyboardEvent::charValue"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, charValue) - 96usize];
+    ["Offset of field: EmscriptenKeyboardEvent::locale"]
+        [::std::mem::offset_of!(EmscriptenKeyboardEvent, locale) - 128usize];
+};
+pub type em_key_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        keyEvent: *const EmscriptenKeyboardEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_keypress_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_key_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_keydown_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_key_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_keyup_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_key_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenMouseEvent {
+    pub timestamp: f64,
+    pub screenX: ::std::os::raw::c_int,
+    pub screenY: ::std::os::raw::c_int,
+    pub clientX: ::std::os::raw::c_int,
+    pub clientY: ::std::os::raw::c_int,
+    pub ctrlKey: bool,
+    pub shiftKey: bool,
+    pub altKey: bool,
+    pub metaKey: bool,
+    pub button: ::std::os::raw::c_ushort,
+    pub buttons: ::std::os::raw::c_ushort,
+    pub movementX: ::std::os::raw::c_int,
+    pub movementY: ::std::os::raw::c_int,
+    pub targetX: ::std::os::raw::c_int,
+    pub targetY: ::std::os::raw::c_int,
+    pub canvasX: ::std::os::raw::c_int,
+    pub canvasY: ::std::os::raw::c_int,
+    pub padding: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenMouseEvent"][::std::mem::size_of::<EmscriptenMouseEvent>() - 64usize];
+    ["Alignment of EmscriptenMouseEvent"][::std::mem::align_of::<EmscriptenMouseEvent>() - 8usize];
+    ["Offset of field: EmscriptenMouseEvent::timestamp"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, timestamp) - 0usize];
+    ["Offset of field: EmscriptenMouseEvent::screenX"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, screenX) - 8usize];
+    ["Offset of field: EmscriptenMouseEvent::screenY"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, screenY) - 12usize];
+    ["Offset of field: EmscriptenMouseEvent::clientX"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, clientX) - 16usize];
+    ["Offset of field: EmscriptenMouseEvent::clientY"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, clientY) - 20usize];
+    ["Offset of field: EmscriptenMouseEvent::ctrlKey"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, ctrlKey) - 24usize];
+    ["Offset of field: EmscriptenMouseEvent::shiftKey"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, shiftKey) - 25usize];
+    ["Offset of field: EmscriptenMouseEvent::altKey"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, altKey) - 26usize];
+    ["Offset of field: EmscriptenMouseEvent::metaKey"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, metaKey) - 27usize];
+    ["Offset of field: EmscriptenMouseEvent::button"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, button) - 28usize];
+    ["Offset of field: EmscriptenMouseEvent::buttons"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, buttons) - 30usize];
+    ["Offset of field: EmscriptenMouseEvent::movementX"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, movementX) - 32usize];
+    ["Offset of field: EmscriptenMouseEvent::movementY"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, movementY) - 36usize];
+    ["Offset of field: EmscriptenMouseEvent::targetX"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, targetX) - 40usize];
+    ["Offset of field: EmscriptenMouseEvent::targetY"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, targetY) - 44usize];
+    ["Offset of field: EmscriptenMouseEvent::canvasX"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, canvasX) - 48usize];
+    ["Offset of field: EmscriptenMouseEvent::canvasY"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, canvasY) - 52usize];
+    ["Offset of field: EmscriptenMouseEvent::padding"]
+        [::std::mem::offset_of!(EmscriptenMouseEvent, padding) - 56usize];
+};
+pub type em_mouse_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        mouseEvent: *const EmscriptenMouseEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_click_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mousedown_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mouseup_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_dblclick_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mousemove_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mouseenter_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mouseleave_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mouseover_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_mouseout_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_mouse_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_mouse_status(
+        mouseState: *mut EmscriptenMouseEvent,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenWheelEvent {
+    pub mouse: EmscriptenMouseEvent,
+    pub deltaX: f64,
+    pub deltaY: f64,
+    pub deltaZ: f64,
+    pub deltaMode: ::std::os::raw::c_uint,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWheelEvent"][::std::mem::size_of::<EmscriptenWheelEvent>() - 96usize];
+    ["Alignment of EmscriptenWheelEvent"][::std::mem::align_of::<EmscriptenWheelEvent>() - 8usize];
+    ["Offset of field: EmscriptenWheelEvent::mouse"]
+        [::std::mem::offset_of!(EmscriptenWheelEvent, mouse) - 0usize];
+    ["Offset of field: EmscriptenWheelEvent::deltaX"]
+        [::std::mem::offset_of!(EmscriptenWheelEvent, deltaX) - 64usize];
+    ["Offset of field: EmscriptenWheelEvent::deltaY"]
+        [::std::mem::offset_of!(EmscriptenWheelEvent, deltaY) - 72usize];
+    ["Offset of field: EmscriptenWheelEvent::deltaZ"]
+        [::std::mem::offset_of!(EmscriptenWheelEvent, deltaZ) - 80usize];
+    ["Offset of field: EmscriptenWheelEvent::deltaMode"]
+        [::std::mem::offset_of!(EmscriptenWheelEvent, deltaMode) - 88usize];
+};
+pub type em_wheel_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        wheelEvent: *const EmscriptenWheelEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_wheel_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_wheel_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenUiEvent {
+    pub detail: ::std::os::raw::c_int,
+    pub documentBodyClientWidth: ::std::os::raw::c_int,
+    pub documentBodyClientHeight: ::std::os::raw::c_int,
+    pub windowInnerWidth: ::std::os::raw::c_int,
+    pub windowInnerHeight: ::std::os::raw::c_int,
+    pub windowOuterWidth: ::std::os::raw::c_int,
+    pub windowOuterHeight: ::std::os::raw::c_int,
+    pub scrollTop: ::std::os::raw::c_int,
+    pub scrollLeft: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenUiEvent"][::std::mem::size_of::<EmscriptenUiEvent>() - 36usize];
+    ["Alignment of EmscriptenUiEvent"][::std::mem::align_of::<EmscriptenUiEvent>() - 4usize];
+    ["Offset of field: EmscriptenUiEvent::detail"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, detail) - 0usize];
+    ["Offset of field: EmscriptenUiEvent::documentBodyClientWidth"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, documentBodyClientWidth) - 4usize];
+    ["Offset of field: EmscriptenUiEvent::documentBodyClientHeight"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, documentBodyClientHeight) - 8usize];
+    ["Offset of field: EmscriptenUiEvent::windowInnerWidth"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, windowInnerWidth) - 12usize];
+    ["Offset of field: EmscriptenUiEvent::windowInnerHeight"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, windowInnerHeight) - 16usize];
+    ["Offset of field: EmscriptenUiEvent::windowOuterWidth"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, windowOuterWidth) - 20usize];
+    ["Offset of field: EmscriptenUiEvent::windowOuterHeight"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, windowOuterHeight) - 24usize];
+    ["Offset of field: EmscriptenUiEvent::scrollTop"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, scrollTop) - 28usize];
+    ["Offset of field: EmscriptenUiEvent::scrollLeft"]
+        [::std::mem::offset_of!(EmscriptenUiEvent, scrollLeft) - 32usize];
+};
+pub type em_ui_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        uiEvent: *const EmscriptenUiEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_resize_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_ui_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_scroll_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_ui_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenFocusEvent {
+    pub nodeName: [::std::os::raw::c_char; 128usize],
+    pub id: [::std::os::raw::c_char; 128usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenFocusEvent"][::std::mem::size_of::<EmscriptenFocusEvent>() - 256usize];
+    ["Alignment of EmscriptenFocusEvent"][::std::mem::align_of::<EmscriptenFocusEvent>() - 1usize];
+    ["Offset of field: EmscriptenFocusEvent::nodeName"]
+        [::std::mem::offset_of!(EmscriptenFocusEvent, nodeName) - 0usize];
+    ["Offset of field: EmscriptenFocusEvent::id"]
+        [::std::mem::offset_of!(EmscriptenFocusEvent, id) - 128usize];
+};
+impl Default for EmscriptenFocusEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_focus_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        focusEvent: *const EmscriptenFocusEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_blur_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_focus_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_focus_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_focus_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_focusin_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_focus_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_focusout_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_focus_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenDeviceOrientationEvent {
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub absolute: bool,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenDeviceOrientationEvent"]
+        [::std::mem::size_of::<EmscriptenDeviceOrientationEvent>() - 32usize];
+    ["Alignment of EmscriptenDeviceOrientationEvent"]
+        [::std::mem::align_of::<EmscriptenDeviceOrientationEvent>() - 8usize];
+    ["Offset of field: EmscriptenDeviceOrientationEvent::alpha"]
+        [::std::mem::offset_of!(EmscriptenDeviceOrientationEvent, alpha) - 0usize];
+    ["Offset of field: EmscriptenDeviceOrientationEvent::beta"]
+        [::std::mem::offset_of!(EmscriptenDeviceOrientationEvent, beta) - 8usize];
+    ["Offset of field: EmscriptenDeviceOrientationEvent::gamma"]
+        [::std::mem::offset_of!(EmscriptenDeviceOrientationEvent, gamma) - 16usize];
+    ["Offset of field: EmscriptenDeviceOrientationEvent::absolute"]
+        [::std::mem::offset_of!(EmscriptenDeviceOrientationEvent, absolute) - 24usize];
+};
+pub type em_deviceorientation_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        deviceOrientationEvent: *const EmscriptenDeviceOrientationEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_deviceorientation_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_deviceorientation_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_deviceorientation_status(
+        orientationState: *mut EmscriptenDeviceOrientationEvent,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenDeviceMotionEvent {
+    pub accelerationX: f64,
+    pub accelerationY: f64,
+    pub accelerationZ: f64,
+    pub accelerationIncludingGravityX: f64,
+    pub accelerationIncludingGravityY: f64,
+    pub accelerationIncludingGravityZ: f64,
+    pub rotationRateAlpha: f64,
+    pub rotationRateBeta: f64,
+    pub rotationRateGamma: f64,
+    pub supportedFields: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenDeviceMotionEvent"]
+        [::std::mem::size_of::<EmscriptenDeviceMotionEvent>() - 80usize];
+    ["Alignment of EmscriptenDeviceMotionEvent"]
+        [::std::mem::align_of::<EmscriptenDeviceMotionEvent>() - 8usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationX"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, accelerationX) - 0usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationY"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, accelerationY) - 8usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationZ"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, accelerationZ) - 16usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationIncludingGravityX"][::std::mem::offset_of!(
+        EmscriptenDeviceMotionEvent,
+        accelerationIncludingGravityX
+    ) - 24usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationIncludingGravityY"][::std::mem::offset_of!(
+        EmscriptenDeviceMotionEvent,
+        accelerationIncludingGravityY
+    ) - 32usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::accelerationIncludingGravityZ"][::std::mem::offset_of!(
+        EmscriptenDeviceMotionEvent,
+        accelerationIncludingGravityZ
+    ) - 40usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::rotationRateAlpha"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, rotationRateAlpha) - 48usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::rotationRateBeta"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, rotationRateBeta) - 56usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::rotationRateGamma"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, rotationRateGamma) - 64usize];
+    ["Offset of field: EmscriptenDeviceMotionEvent::supportedFields"]
+        [::std::mem::offset_of!(EmscriptenDeviceMotionEvent, supportedFields) - 72usize];
+};
+pub type em_devicemotion_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        deviceMotionEvent: *const EmscriptenDeviceMotionEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_devicemotion_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_devicemotion_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_devicemotion_status(
+        motionState: *mut EmscriptenDeviceMotionEvent,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenOrientationChangeEvent {
+    pub orientationIndex: ::std::os::raw::c_int,
+    pub orientationAngle: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenOrientationChangeEvent"]
+        [::std::mem::size_of::<EmscriptenOrientationChangeEvent>() - 8usize];
+    ["Alignment of EmscriptenOrientationChangeEvent"]
+        [::std::mem::align_of::<EmscriptenOrientationChangeEvent>() - 4usize];
+    ["Offset of field: EmscriptenOrientationChangeEvent::orientationIndex"]
+        [::std::mem::offset_of!(EmscriptenOrientationChangeEvent, orientationIndex) - 0usize];
+    ["Offset of field: EmscriptenOrientationChangeEvent::orientationAngle"]
+        [::std::mem::offset_of!(EmscriptenOrientationChangeEvent, orientationAngle) - 4usize];
+};
+pub type em_orientationchange_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        orientationChangeEvent: *const EmscriptenOrientationChangeEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_orientationchange_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_orientationchange_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_orientation_status(
+        orientationStatus: *mut EmscriptenOrientationChangeEvent,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_orientation(
+        allowedOrientations: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_unlock_orientation() -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenFullscreenChangeEvent {
+    pub isFullscreen: bool,
+    pub fullscreenEnabled: bool,
+    pub nodeName: [::std::os::raw::c_char; 128usize],
+    pub id: [::std::os::raw::c_char; 128usize],
+    pub elementWidth: ::std::os::raw::c_int,
+    pub elementHeight: ::std::os::raw::c_int,
+    pub screenWidth: ::std::os::raw::c_int,
+    pub screenHeight: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenFullscreenChangeEvent"]
+        [::std::mem::size_of::<EmscriptenFullscreenChangeEvent>() - 276usize];
+    ["Alignment of EmscriptenFullscreenChangeEvent"]
+        [::std::mem::align_of::<EmscriptenFullscreenChangeEvent>() - 4usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::isFullscreen"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, isFullscreen) - 0usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::fullscreenEnabled"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, fullscreenEnabled) - 1usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::nodeName"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, nodeName) - 2usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::id"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, id) - 130usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::elementWidth"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, elementWidth) - 260usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::elementHeight"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, elementHeight) - 264usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::screenWidth"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, screenWidth) - 268usize];
+    ["Offset of field: EmscriptenFullscreenChangeEvent::screenHeight"]
+        [::std::mem::offset_of!(EmscriptenFullscreenChangeEvent, screenHeight) - 272usize];
+};
+impl Default for EmscriptenFullscreenChangeEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_fullscreenchange_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        fullscreenChangeEvent: *const EmscriptenFullscreenChangeEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_fullscreenchange_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_fullscreenchange_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_fullscreen_status(
+        fullscreenStatus: *mut EmscriptenFullscreenChangeEvent,
+    ) -> ::std::os::raw::c_int;
+}
+pub type em_canvasresized_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        reserved: *const ::std::os::raw::c_void,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenFullscreenStrategy {
+    pub scaleMode: ::std::os::raw::c_int,
+    pub canvasResolutionScaleMode: ::std::os::raw::c_int,
+    pub filteringMode: ::std::os::raw::c_int,
+    pub canvasResizedCallback: em_canvasresized_callback_func,
+    pub canvasResizedCallbackUserData: *mut ::std::os::raw::c_void,
+    pub canvasResizedCallbackTargetThread: pthread_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenFullscreenStrategy"]
+        [::std::mem::size_of::<EmscriptenFullscreenStrategy>() - 24usize];
+    ["Alignment of EmscriptenFullscreenStrategy"]
+        [::std::mem::align_of::<EmscriptenFullscreenStrategy>() - 4usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::scaleMode"]
+        [::std::mem::offset_of!(EmscriptenFullscreenStrategy, scaleMode) - 0usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::canvasResolutionScaleMode"]
+        [::std::mem::offset_of!(EmscriptenFullscreenStrategy, canvasResolutionScaleMode) - 4usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::filteringMode"]
+        [::std::mem::offset_of!(EmscriptenFullscreenStrategy, filteringMode) - 8usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::canvasResizedCallback"]
+        [::std::mem::offset_of!(EmscriptenFullscreenStrategy, canvasResizedCallback) - 12usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::canvasResizedCallbackUserData"][::std::mem::offset_of!(
+        EmscriptenFullscreenStrategy,
+        canvasResizedCallbackUserData
+    ) - 16usize];
+    ["Offset of field: EmscriptenFullscreenStrategy::canvasResizedCallbackTargetThread"][::std::mem::offset_of!(
+        EmscriptenFullscreenStrategy,
+        canvasResizedCallbackTargetThread
+    )
+        - 20usize];
+};
+impl Default for EmscriptenFullscreenStrategy {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_request_fullscreen(
+        target: *const ::std::os::raw::c_char,
+        deferUntilInEventHandler: bool,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_request_fullscreen_strategy(
+        target: *const ::std::os::raw::c_char,
+        deferUntilInEventHandler: bool,
+        fullscreenStrategy: *const EmscriptenFullscreenStrategy,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_exit_fullscreen() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_enter_soft_fullscreen(
+        target: *const ::std::os::raw::c_char,
+        fullscreenStrategy: *const EmscriptenFullscreenStrategy,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_exit_soft_fullscreen() -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenPointerlockChangeEvent {
+    pub isActive: bool,
+    pub nodeName: [::std::os::raw::c_char; 128usize],
+    pub id: [::std::os::raw::c_char; 128usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenPointerlockChangeEvent"]
+        [::std::mem::size_of::<EmscriptenPointerlockChangeEvent>() - 257usize];
+    ["Alignment of EmscriptenPointerlockChangeEvent"]
+        [::std::mem::align_of::<EmscriptenPointerlockChangeEvent>() - 1usize];
+    ["Offset of field: EmscriptenPointerlockChangeEvent::isActive"]
+        [::std::mem::offset_of!(EmscriptenPointerlockChangeEvent, isActive) - 0usize];
+    ["Offset of field: EmscriptenPointerlockChangeEvent::nodeName"]
+        [::std::mem::offset_of!(EmscriptenPointerlockChangeEvent, nodeName) - 1usize];
+    ["Offset of field: EmscriptenPointerlockChangeEvent::id"]
+        [::std::mem::offset_of!(EmscriptenPointerlockChangeEvent, id) - 129usize];
+};
+impl Default for EmscriptenPointerlockChangeEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_pointerlockchange_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        pointerlockChangeEvent: *const EmscriptenPointerlockChangeEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_pointerlockchange_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_pointerlockchange_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+pub type em_pointerlockerror_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        reserved: *const ::std::os::raw::c_void,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_pointerlockerror_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_pointerlockerror_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_pointerlock_status(
+        pointerlockStatus: *mut EmscriptenPointerlockChangeEvent,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_request_pointerlock(
+        target: *const ::std::os::raw::c_char,
+        deferUntilInEventHandler: bool,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_exit_pointerlock() -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenVisibilityChangeEvent {
+    pub hidden: bool,
+    pub visibilityState: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenVisibilityChangeEvent"]
+        [::std::mem::size_of::<EmscriptenVisibilityChangeEvent>() - 8usize];
+    ["Alignment of EmscriptenVisibilityChangeEvent"]
+        [::std::mem::align_of::<EmscriptenVisibilityChangeEvent>() - 4usize];
+    ["Offset of field: EmscriptenVisibilityChangeEvent::hidden"]
+        [::std::mem::offset_of!(EmscriptenVisibilityChangeEvent, hidden) - 0usize];
+    ["Offset of field: EmscriptenVisibilityChangeEvent::visibilityState"]
+        [::std::mem::offset_of!(EmscriptenVisibilityChangeEvent, visibilityState) - 4usize];
+};
+pub type em_visibilitychange_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        visibilityChangeEvent: *const EmscriptenVisibilityChangeEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_visibilitychange_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_visibilitychange_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_visibility_status(
+        visibilityStatus: *mut EmscriptenVisibilityChangeEvent,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenTouchPoint {
+    pub identifier: ::std::os::raw::c_int,
+    pub screenX: ::std::os::raw::c_int,
+    pub screenY: ::std::os::raw::c_int,
+    pub clientX: ::std::os::raw::c_int,
+    pub clientY: ::std::os::raw::c_int,
+    pub pageX: ::std::os::raw::c_int,
+    pub pageY: ::std::os::raw::c_int,
+    pub isChanged: bool,
+    pub onTarget: bool,
+    pub targetX: ::std::os::raw::c_int,
+    pub targetY: ::std::os::raw::c_int,
+    pub canvasX: ::std::os::raw::c_int,
+    pub canvasY: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenTouchPoint"][::std::mem::size_of::<EmscriptenTouchPoint>() - 48usize];
+    ["Alignment of EmscriptenTouchPoint"][::std::mem::align_of::<EmscriptenTouchPoint>() - 4usize];
+    ["Offset of field: EmscriptenTouchPoint::identifier"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, identifier) - 0usize];
+    ["Offset of field: EmscriptenTouchPoint::screenX"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, screenX) - 4usize];
+    ["Offset of field: EmscriptenTouchPoint::screenY"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, screenY) - 8usize];
+    ["Offset of field: EmscriptenTouchPoint::clientX"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, clientX) - 12usize];
+    ["Offset of field: EmscriptenTouchPoint::clientY"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, clientY) - 16usize];
+    ["Offset of field: EmscriptenTouchPoint::pageX"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, pageX) - 20usize];
+    ["Offset of field: EmscriptenTouchPoint::pageY"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, pageY) - 24usize];
+    ["Offset of field: EmscriptenTouchPoint::isChanged"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, isChanged) - 28usize];
+    ["Offset of field: EmscriptenTouchPoint::onTarget"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, onTarget) - 29usize];
+    ["Offset of field: EmscriptenTouchPoint::targetX"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, targetX) - 32usize];
+    ["Offset of field: EmscriptenTouchPoint::targetY"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, targetY) - 36usize];
+    ["Offset of field: EmscriptenTouchPoint::canvasX"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, canvasX) - 40usize];
+    ["Offset of field: EmscriptenTouchPoint::canvasY"]
+        [::std::mem::offset_of!(EmscriptenTouchPoint, canvasY) - 44usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenTouchEvent {
+    pub timestamp: f64,
+    pub numTouches: ::std::os::raw::c_int,
+    pub ctrlKey: bool,
+    pub shiftKey: bool,
+    pub altKey: bool,
+    pub metaKey: bool,
+    pub touches: [EmscriptenTouchPoint; 32usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenTouchEvent"][::std::mem::size_of::<EmscriptenTouchEvent>() - 1552usize];
+    ["Alignment of EmscriptenTouchEvent"][::std::mem::align_of::<EmscriptenTouchEvent>() - 8usize];
+    ["Offset of field: EmscriptenTouchEvent::timestamp"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, timestamp) - 0usize];
+    ["Offset of field: EmscriptenTouchEvent::numTouches"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, numTouches) - 8usize];
+    ["Offset of field: EmscriptenTouchEvent::ctrlKey"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, ctrlKey) - 12usize];
+    ["Offset of field: EmscriptenTouchEvent::shiftKey"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, shiftKey) - 13usize];
+    ["Offset of field: EmscriptenTouchEvent::altKey"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, altKey) - 14usize];
+    ["Offset of field: EmscriptenTouchEvent::metaKey"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, metaKey) - 15usize];
+    ["Offset of field: EmscriptenTouchEvent::touches"]
+        [::std::mem::offset_of!(EmscriptenTouchEvent, touches) - 16usize];
+};
+pub type em_touch_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        touchEvent: *const EmscriptenTouchEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_touchstart_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_touch_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_touchend_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_touch_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_touchmove_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_touch_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_touchcancel_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_touch_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenGamepadEvent {
+    pub timestamp: f64,
+    pub numAxes: ::std::os::raw::c_int,
+    pub numButtons: ::std::os::raw::c_int,
+    pub axis: [f64; 64usize],
+    pub analogButton: [f64; 64usize],
+    pub digitalButton: [bool; 64usize],
+    pub connected: bool,
+    pub index: ::std::os::raw::c_int,
+    pub id: [::std::os::raw::c_char; 64usize],
+    pub mapping: [::std::os::raw::c_char; 64usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenGamepadEvent"][::std::mem::size_of::<EmscriptenGamepadEvent>() - 1240usize];
+    ["Alignment of EmscriptenGamepadEvent"]
+        [::std::mem::align_of::<EmscriptenGamepadEvent>() - 8usize];
+    ["Offset of field: EmscriptenGamepadEvent::timestamp"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, timestamp) - 0usize];
+    ["Offset of field: EmscriptenGamepadEvent::numAxes"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, numAxes) - 8usize];
+    ["Offset of field: EmscriptenGamepadEvent::numButtons"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, numButtons) - 12usize];
+    ["Offset of field: EmscriptenGamepadEvent::axis"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, axis) - 16usize];
+    ["Offset of field: EmscriptenGamepadEvent::analogButton"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, analogButton) - 528usize];
+    ["Offset of field: EmscriptenGamepadEvent::digitalButton"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, digitalButton) - 1040usize];
+    ["Offset of field: EmscriptenGamepadEvent::connected"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, connected) - 1104usize];
+    ["Offset of field: EmscriptenGamepadEvent::index"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, index) - 1108usize];
+    ["Offset of field: EmscriptenGamepadEvent::id"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, id) - 1112usize];
+    ["Offset of field: EmscriptenGamepadEvent::mapping"]
+        [::std::mem::offset_of!(EmscriptenGamepadEvent, mapping) - 1176usize];
+};
+impl Default for EmscriptenGamepadEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_gamepad_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        gamepadEvent: *const EmscriptenGamepadEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_gamepadconnected_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_gamepad_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_gamepaddisconnected_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_gamepad_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_sample_gamepad_data() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_num_gamepads() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_gamepad_status(
+        index: ::std::os::raw::c_int,
+        gamepadState: *mut EmscriptenGamepadEvent,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenBatteryEvent {
+    pub chargingTime: f64,
+    pub dischargingTime: f64,
+    pub level: f64,
+    pub charging: bool,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenBatteryEvent"][::std::mem::size_of::<EmscriptenBatteryEvent>() - 32usize];
+    ["Alignment of EmscriptenBatteryEvent"]
+        [::std::mem::align_of::<EmscriptenBatteryEvent>() - 8usize];
+    ["Offset of field: EmscriptenBatteryEvent::chargingTime"]
+        [::std::mem::offset_of!(EmscriptenBatteryEvent, chargingTime) - 0usize];
+    ["Offset of field: EmscriptenBatteryEvent::dischargingTime"]
+        [::std::mem::offset_of!(EmscriptenBatteryEvent, dischargingTime) - 8usize];
+    ["Offset of field: EmscriptenBatteryEvent::level"]
+        [::std::mem::offset_of!(EmscriptenBatteryEvent, level) - 16usize];
+    ["Offset of field: EmscriptenBatteryEvent::charging"]
+        [::std::mem::offset_of!(EmscriptenBatteryEvent, charging) - 24usize];
+};
+pub type em_battery_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        batteryEvent: *const EmscriptenBatteryEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_batterychargingchange_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_battery_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_batterylevelchange_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_battery_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_battery_status(
+        batteryState: *mut EmscriptenBatteryEvent,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_vibrate(msecs: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_vibrate_pattern(
+        msecsArray: *mut ::std::os::raw::c_int,
+        numEntries: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+pub type em_beforeunload_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        reserved: *const ::std::os::raw::c_void,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> *const ::std::os::raw::c_char,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_beforeunload_callback_on_thread(
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_beforeunload_callback,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_canvas_element_size(
+        target: *const ::std::os::raw::c_char,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_canvas_element_size(
+        target: *const ::std::os::raw::c_char,
+        width: *mut ::std::os::raw::c_int,
+        height: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_element_css_size(
+        target: *const ::std::os::raw::c_char,
+        width: f64,
+        height: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_get_element_css_size(
+        target: *const ::std::os::raw::c_char,
+        width: *mut f64,
+        height: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_html5_remove_all_event_listeners();
+}
+unsafe extern "C" {
+    pub fn emscripten_request_animation_frame(
+        cb: ::std::option::Option<
+            unsafe extern "C" fn(time: f64, userData: *mut ::std::os::raw::c_void) -> bool,
+        >,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_cancel_animation_frame(requestAnimationFrameId: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_request_animation_frame_loop(
+        cb: ::std::option::Option<
+            unsafe extern "C" fn(time: f64, userData: *mut ::std::os::raw::c_void) -> bool,
+        >,
+        userData: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_date_now() -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_performance_now() -> f64;
+}
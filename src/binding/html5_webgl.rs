@@ -0,0 +1,306 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_WEBGL_CONTEXT_PROXY_DISALLOW: u32 = 0;
+pub const EMSCRIPTEN_WEBGL_CONTEXT_PROXY_FALLBACK: u32 = 1;
+pub const EMSCRIPTEN_WEBGL_CONTEXT_PROXY_ALWAYS: u32 = 2;
+pub const EM_WEBGL_POWER_PREFERENCE_DEFAULT: u32 = 0;
+pub const EM_WEBGL_POWER_PREFERENCE_LOW_POWER: u32 = 1;
+pub const EM_WEBGL_POWER_PREFERENCE_HIGH_PERFORMANCE: u32 = 2;
+pub const EMSCRIPTEN_WEBGL_PARAM_TYPE_INT: u32 = 0;
+pub const EMSCRIPTEN_WEBGL_PARAM_TYPE_FLOAT: u32 = 1;
+pub type EMSCRIPTEN_WEBGL_CONTEXT_HANDLE = usize;
+pub type EMSCRIPTEN_WEBGL_CONTEXT_PROXY_MODE = ::std::os::raw::c_int;
+pub type EM_WEBGL_POWER_PREFERENCE = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenWebGLContextAttributes {
+    pub alpha: bool,
+    pub depth: bool,
+    pub stencil: bool,
+    pub antialias: bool,
+    pub premultipliedAlpha: bool,
+    pub preserveDrawingBuffer: bool,
+    pub powerPreference: EM_WEBGL_POWER_PREFERENCE,
+    pub failIfMajorPerformanceCaveat: bool,
+    pub majorVersion: ::std::os::raw::c_int,
+    pub minorVersion: ::std::os::raw::c_int,
+    pub enableExtensionsByDefault: bool,
+    pub explicitSwapControl: bool,
+    pub proxyContextToMainThread: EMSCRIPTEN_WEBGL_CONTEXT_PROXY_MODE,
+    pub renderViaOffscreenBackBuffer: bool,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebGLContextAttributes"]
+        [::std::mem::size_of::<EmscriptenWebGLContextAttributes>() - 36usize];
+    ["Alignment of EmscriptenWebGLContextAttributes"]
+        [::std::mem::align_of::<EmscriptenWebGLContextAttributes>() - 4usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::alpha"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, alpha) - 0usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::depth"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, depth) - 1usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::stencil"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, stencil) - 2usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::antialias"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, antialias) - 3usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::premultipliedAlpha"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, premultipliedAlpha) - 4usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::preserveDrawingBuffer"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, preserveDrawingBuffer) - 5usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::powerPreference"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, powerPreference) - 8usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::failIfMajorPerformanceCaveat"][::std::mem::offset_of!(
+        EmscriptenWebGLContextAttributes,
+        failIfMajorPerformanceCaveat
+    )
+        - 12usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::majorVersion"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, majorVersion) - 16usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::minorVersion"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, minorVersion) - 20usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::enableExtensionsByDefault"][::std::mem::offset_of!(
+        EmscriptenWebGLContextAttributes,
+        enableExtensionsByDefault
+    ) - 24usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::explicitSwapControl"]
+        [::std::mem::offset_of!(EmscriptenWebGLContextAttributes, explicitSwapControl) - 25usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::proxyContextToMainThread"][::std::mem::offset_of!(
+        EmscriptenWebGLContextAttributes,
+        proxyContextToMainThread
+    ) - 28usize];
+    ["Offset of field: EmscriptenWebGLContextAttributes::renderViaOffscreenBackBuffer"][::std::mem::offset_of!(
+        EmscriptenWebGLContextAttributes,
+        renderViaOffscreenBackBuffer
+    )
+        - 32usize];
+};
+unsafe extern "C" {
+    pub fn emscripten_webgl_init_context_attributes(
+        attributes: *mut EmscriptenWebGLContextAttributes,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_create_context(
+        target: *const ::std::os::raw::c_char,
+        attributes: *const EmscriptenWebGLContextAttributes,
+    ) -> EMSCRIPTEN_WEBGL_CONTEXT_HANDLE;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_make_context_current(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_current_context() -> EMSCRIPTEN_WEBGL_CONTEXT_HANDLE;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_drawing_buffer_size(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+        width: *mut ::std::os::raw::c_int,
+        height: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_context_attributes(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+        outAttributes: *mut EmscriptenWebGLContextAttributes,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_destroy_context(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_extension(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+        extension: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_ANGLE_instanced_arrays(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_OES_vertex_array_object(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_WEBGL_draw_buffers(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_WEBGL_draw_instanced_base_vertex_base_instance(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_WEBGL_multi_draw(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_WEBGL_multi_draw_instanced_base_vertex_base_instance(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_EXT_polygon_offset_clamp(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_EXT_clip_control(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_enable_WEBGL_polygon_mode(
+        context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE,
+    ) -> bool;
+}
+pub type em_webgl_context_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        reserved: *const ::std::os::raw::c_void,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_set_webglcontextlost_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_webgl_context_callback,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_set_webglcontextrestored_callback_on_thread(
+        target: *const ::std::os::raw::c_char,
+        userData: *mut ::std::os::raw::c_void,
+        useCapture: bool,
+        callback: em_webgl_context_callback,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_is_webgl_context_lost(context: EMSCRIPTEN_WEBGL_CONTEXT_HANDLE) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_commit_frame() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_supports_offscreencanvas() -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl1_get_proc_address(
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl2_get_proc_address(
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_proc_address(
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_supported_extensions() -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_shader_parameter_d(
+        shader: ::std::os::raw::c_int,
+        param: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_shader_info_log_utf8(
+        shader: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_shader_source_utf8(
+        shader: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_program_parameter_d(
+        program: ::std::os::raw::c_int,
+        param: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_program_info_log_utf8(
+        program: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_vertex_attrib_d(
+        index: ::std::os::raw::c_int,
+        param: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_vertex_attrib_o(
+        index: ::std::os::raw::c_int,
+        param: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_vertex_attrib_v(
+        index: ::std::os::raw::c_int,
+        param: ::std::os::raw::c_int,
+        dst: *mut ::std::os::raw::c_void,
+        dstLength: ::std::os::raw::c_int,
+        dstType: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_uniform_d(
+        program: ::std::os::raw::c_int,
+        location: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_uniform_v(
+        program: ::std::os::raw::c_int,
+        location: ::std::os::raw::c_int,
+        dst: *mut ::std::os::raw::c_void,
+        dstLength: ::std::os::raw::c_int,
+        dstType: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_parameter_v(
+        param: ::std::os::raw::c_int,
+        dst: *mut ::std::os::raw::c_void,
+        dstLength: ::std::os::raw::c_int,
+        dstType: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_parameter_d(param: ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_parameter_o(param: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_parameter_utf8(
+        param: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgl_get_parameter_i64v(
+        param: ::std::os::raw::c_int,
+        dst: *mut ::std::os::raw::c_longlong,
+    );
+}
@@ -0,0 +1,172 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_webgpu_get_device() -> WGPUDevice;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_release_js_handle(js_handle: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_surface(arg1: ::std::os::raw::c_int) -> WGPUSurface;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_surface(arg1: WGPUSurface) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_swap_chain(arg1: ::std::os::raw::c_int) -> WGPUSwapChain;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_swap_chain(arg1: WGPUSwapChain) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_device(arg1: ::std::os::raw::c_int) -> WGPUDevice;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_device(arg1: WGPUDevice) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_queue(arg1: ::std::os::raw::c_int) -> WGPUQueue;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_queue(arg1: WGPUQueue) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_command_buffer(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUCommandBuffer;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_command_buffer(
+        arg1: WGPUCommandBuffer,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_command_encoder(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUCommandEncoder;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_command_encoder(
+        arg1: WGPUCommandEncoder,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_render_pass_encoder(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPURenderPassEncoder;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_render_pass_encoder(
+        arg1: WGPURenderPassEncoder,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_compute_pass_encoder(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUComputePassEncoder;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_compute_pass_encoder(
+        arg1: WGPUComputePassEncoder,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_bind_group(arg1: ::std::os::raw::c_int) -> WGPUBindGroup;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_bind_group(arg1: WGPUBindGroup) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_buffer(arg1: ::std::os::raw::c_int) -> WGPUBuffer;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_buffer(arg1: WGPUBuffer) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_sampler(arg1: ::std::os::raw::c_int) -> WGPUSampler;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_sampler(arg1: WGPUSampler) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_texture(arg1: ::std::os::raw::c_int) -> WGPUTexture;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_texture(arg1: WGPUTexture) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_texture_view(arg1: ::std::os::raw::c_int) -> WGPUTextureView;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_texture_view(arg1: WGPUTextureView) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_query_set(arg1: ::std::os::raw::c_int) -> WGPUQuerySet;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_query_set(arg1: WGPUQuerySet) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_bind_group_layout(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUBindGroupLayout;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_bind_group_layout(
+        arg1: WGPUBindGroupLayout,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_pipeline_layout(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUPipelineLayout;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_pipeline_layout(
+        arg1: WGPUPipelineLayout,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_render_pipeline(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPURenderPipeline;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_render_pipeline(
+        arg1: WGPURenderPipeline,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_compute_pipeline(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPUComputePipeline;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_compute_pipeline(
+        arg1: WGPUComputePipeline,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_shader_module(arg1: ::std::os::raw::c_int) -> WGPUShaderModule;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_shader_module(arg1: WGPUShaderModule) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_render_bundle_encoder(
+        arg1: ::std::os::raw::c_int,
+    ) -> WGPURenderBundleEncoder;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_render_bundle_encoder(
+        arg1: WGPURenderBundleEncoder,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_import_render_bundle(arg1: ::std::os::raw::c_int) -> WGPURenderBundle;
+}
+unsafe extern "C" {
+    pub fn emscripten_webgpu_export_render_bundle(arg1: WGPURenderBundle) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,28 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const DOM_VK_WIN_OEM_FJ_JISHO: u32 = 146;
+pub const DOM_VK_WIN_OEM_FJ_MASSHOU: u32 = 147;
+pub const DOM_VK_WIN_OEM_FJ_TOUROKU: u32 = 148;
+pub const DOM_VK_WIN_OEM_FJ_LOYA: u32 = 149;
+pub const DOM_VK_WIN_OEM_FJ_ROYA: u32 = 150;
+pub const DOM_VK_WIN_OEM_RESET: u32 = 233;
+pub const DOM_VK_WIN_OEM_JUMP: u32 = 234;
+pub const DOM_VK_WIN_OEM_PA1: u32 = 235;
+pub const DOM_VK_WIN_OEM_PA2: u32 = 236;
+pub const DOM_VK_WIN_OEM_PA3: u32 = 237;
+pub const DOM_VK_WIN_OEM_WSCTRL: u32 = 238;
+pub const DOM_VK_WIN_OEM_CUSEL: u32 = 239;
+pub const DOM_VK_WIN_OEM_ATTN: u32 = 240;
+pub const DOM_VK_WIN_OEM_FINISH: u32 = 241;
+pub const DOM_VK_WIN_OEM_COPY: u32 = 242;
+pub const DOM_VK_WIN_OEM_AUTO: u32 = 243;
+pub const DOM_VK_WIN_OEM_ENLW: u32 = 244;
+pub const DOM_VK_WIN_OEM_BACKTAB: u32 = 245;
+pub const DOM_VK_WIN_OEM_CLEAR: u32 = 254;
+unsafe extern "C" {
+    pub fn emscripten_dom_vk_to_string(
+        dom_vk_code: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
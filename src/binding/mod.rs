@@ -0,0 +1,61 @@
+/* automatically generated by build.rs, one module per emscripten header */
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(unused_imports)]
+
+pub mod atomic;
+pub use atomic::*;
+pub mod console;
+pub use console::*;
+pub mod dom_pk_codes;
+pub use dom_pk_codes::*;
+pub mod em_asm;
+pub use em_asm::*;
+pub mod em_math;
+pub use em_math::*;
+pub mod em_types;
+pub use em_types::*;
+pub mod emscripten;
+pub use emscripten::*;
+pub mod eventloop;
+pub use eventloop::*;
+pub mod external;
+pub use external::*;
+pub mod fetch;
+pub use fetch::*;
+pub mod fiber;
+pub use fiber::*;
+pub mod heap;
+pub use heap::*;
+pub mod html5;
+pub use html5::*;
+pub mod html5_webgl;
+pub use html5_webgl::*;
+pub mod html5_webgpu;
+pub use html5_webgpu::*;
+pub mod key_codes;
+pub use key_codes::*;
+pub mod posix_socket;
+pub use posix_socket::*;
+pub mod promise;
+pub use promise::*;
+pub mod proxying;
+pub use proxying::*;
+pub mod stack;
+pub use stack::*;
+pub mod threading;
+pub use threading::*;
+pub mod threading_legacy;
+pub use threading_legacy::*;
+pub mod val;
+pub use val::*;
+pub mod version;
+pub use version::*;
+pub mod wasm_worker;
+pub use wasm_worker::*;
+pub mod webaudio;
+pub use webaudio::*;
+pub mod websocket;
+pub use websocket::*;
@@ -0,0 +1,9 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_init_websocket_to_posix_socket_bridge(
+        bridgeUrl: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,97 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _em_promise {
+    _unused: [u8; 0],
+}
+pub type em_promise_t = *mut _em_promise;
+pub const em_promise_result_t_EM_PROMISE_FULFILL: em_promise_result_t = 0;
+pub const em_promise_result_t_EM_PROMISE_MATCH: em_promise_result_t = 1;
+pub const em_promise_result_t_EM_PROMISE_MATCH_RELEASE: em_promise_result_t = 2;
+pub const em_promise_result_t_EM_PROMISE_REJECT: em_promise_result_t = 3;
+pub type em_promise_result_t = ::std::os::raw::c_uint;
+pub type em_promise_callback_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        result: *mut *mut ::std::os::raw::c_void,
+        data: *mut ::std::os::raw::c_void,
+        value: *mut ::std::os::raw::c_void,
+    ) -> em_promise_result_t,
+>;
+unsafe extern "C" {
+    pub fn emscripten_promise_create() -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_destroy(promise: em_promise_t);
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_resolve(
+        promise: em_promise_t,
+        result: em_promise_result_t,
+        value: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_then(
+        promise: em_promise_t,
+        on_fulfilled: em_promise_callback_t,
+        on_rejected: em_promise_callback_t,
+        data: *mut ::std::os::raw::c_void,
+    ) -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_all(
+        promises: *mut em_promise_t,
+        results: *mut *mut ::std::os::raw::c_void,
+        num_promises: usize,
+    ) -> em_promise_t;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct em_settled_result_t {
+    pub result: em_promise_result_t,
+    pub value: *mut ::std::os::raw::c_void,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of em_settled_result_t"][::std::mem::size_of::<em_settled_result_t>() - 8usize];
+    ["Alignment of em_settled_result_t"][::std::mem::align_of::<em_settled_result_t>() - 4usize];
+    ["Offset of field: em_settled_result_t::result"]
+        [::std::mem::offset_of!(em_settled_result_t, result) - 0usize];
+    ["Offset of field: em_settled_result_t::value"]
+        [::std::mem::offset_of!(em_settled_result_t, value) - 4usize];
+};
+impl Default for em_settled_result_t {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_all_settled(
+        promises: *mut em_promise_t,
+        results: *mut em_settled_result_t,
+        num_promises: usize,
+    ) -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_any(
+        promises: *mut em_promise_t,
+        errors: *mut *mut ::std::os::raw::c_void,
+        num_promises: usize,
+    ) -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_race(
+        promises: *mut em_promise_t,
+        num_promises: usize,
+    ) -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_promise_await(promise: em_promise_t) -> em_settled_result_t;
+}
@@ -0,0 +1,95 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct em_proxying_queue {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn em_proxying_queue_create() -> *mut em_proxying_queue;
+}
+unsafe extern "C" {
+    pub fn em_proxying_queue_destroy(q: *mut em_proxying_queue);
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_get_system_queue() -> *mut em_proxying_queue;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_execute_queue(q: *mut em_proxying_queue);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct em_proxying_ctx {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_finish(ctx: *mut em_proxying_ctx);
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_async(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_sync(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_sync_with_ctx(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(arg1: *mut em_proxying_ctx, arg2: *mut ::std::os::raw::c_void),
+        >,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_callback(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        callback: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        cancel: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_callback_with_ctx(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(arg1: *mut em_proxying_ctx, arg2: *mut ::std::os::raw::c_void),
+        >,
+        callback: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        cancel: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_promise(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> em_promise_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_proxy_promise_with_ctx(
+        q: *mut em_proxying_queue,
+        target_thread: pthread_t,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(arg1: *mut em_proxying_ctx, arg2: *mut ::std::os::raw::c_void),
+        >,
+        arg: *mut ::std::os::raw::c_void,
+    ) -> em_promise_t;
+}
@@ -0,0 +1,25 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_stack_get_base() -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_stack_get_end() -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_stack_init();
+}
+unsafe extern "C" {
+    pub fn emscripten_stack_set_limits(
+        base: *mut ::std::os::raw::c_void,
+        end: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_stack_get_current() -> usize;
+}
+unsafe extern "C" {
+    pub fn emscripten_stack_get_free() -> usize;
+}
@@ -0,0 +1,59 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_has_threading_support() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_num_logical_cores() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_futex_wait(
+        addr: *mut ::std::os::raw::c_void,
+        val: u32,
+        maxWaitMilliseconds: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_futex_wake(
+        addr: *mut ::std::os::raw::c_void,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_is_main_runtime_thread() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_is_main_browser_thread() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_main_thread_process_queued_calls();
+}
+unsafe extern "C" {
+    pub fn emscripten_current_thread_process_queued_calls();
+}
+unsafe extern "C" {
+    pub fn emscripten_main_runtime_thread_id() -> pthread_t;
+}
+unsafe extern "C" {
+    pub fn emscripten_thread_sleep(msecs: f64);
+}
+unsafe extern "C" {
+    pub fn emscripten_set_thread_name(threadId: pthread_t, name: *const ::std::os::raw::c_char);
+}
+unsafe extern "C" {
+    pub fn emscripten_pthread_attr_gettransferredcanvases(
+        a: *const pthread_attr_t,
+        str_: *mut *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_pthread_attr_settransferredcanvases(
+        a: *mut pthread_attr_t,
+        str_: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_check_blocking_allowed();
+}
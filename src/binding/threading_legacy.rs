@@ -0,0 +1,103 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EM_QUEUED_JS_CALL_MAX_ARGS: u32 = 20;
+pub const EM_FUNC_SIG_RETURN_VALUE_MASK: u32 = 3758096384;
+pub const EM_FUNC_SIG_RETURN_VALUE_V: u32 = 0;
+pub const EM_FUNC_SIG_RETURN_VALUE_I: u32 = 536870912;
+pub const EM_FUNC_SIG_RETURN_VALUE_J: u32 = 1073741824;
+pub const EM_FUNC_SIG_RETURN_VALUE_F: u32 = 1610612736;
+pub const EM_FUNC_SIG_RETURN_VALUE_D: u32 = 2147483648;
+pub const EM_FUNC_SIG_RETURN_VALUE_P: u32 = 536870912;
+pub const EM_FUNC_SIG_NUM_PARAMETERS_SHIFT: u32 = 25;
+pub const EM_FUNC_SIG_NUM_PARAMETERS_MASK: u32 = 503316480;
+pub const EM_FUNC_SIG_ARGUMENTS_TYPE_MASK: i64 = -4261412865;
+pub const EM_FUNC_SIG_ARGUMENT_TYPE_SIZE_MASK: u32 = 3;
+pub const EM_FUNC_SIG_ARGUMENT_TYPE_SIZE_SHIFT: u32 = 2;
+pub const EM_FUNC_SIG_PARAM_I: u32 = 0;
+pub const EM_FUNC_SIG_PARAM_J: u32 = 1;
+pub const EM_FUNC_SIG_PARAM_F: u32 = 2;
+pub const EM_FUNC_SIG_PARAM_D: u32 = 3;
+pub const EM_FUNC_SIG_PARAM_P: u32 = 0;
+pub const EM_FUNC_SIG_PARAM_B: u32 = 4;
+pub const EM_FUNC_SIG_PARAM_F2I: u32 = 5;
+pub const EM_FUNC_SIG_SPECIAL_INTERNAL: u32 = 16777216;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct em_queued_call {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn emscripten_sync_run_in_main_runtime_thread_(
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_async_run_in_main_runtime_thread_(
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        ...
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_async_waitable_run_in_main_runtime_thread_(
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        ...
+    ) -> *mut em_queued_call;
+}
+unsafe extern "C" {
+    pub fn emscripten_wait_for_call_v(
+        call: *mut em_queued_call,
+        timeoutMSecs: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_wait_for_call_i(
+        call: *mut em_queued_call,
+        timeoutMSecs: f64,
+        outResult: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_async_waitable_close(call: *mut em_queued_call);
+}
+unsafe extern "C" {
+    pub fn emscripten_dispatch_to_thread_args(
+        target_thread: pthread_t,
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        satellite: *mut ::std::os::raw::c_void,
+        args: va_list,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_dispatch_to_thread_(
+        target_thread: pthread_t,
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        satellite: *mut ::std::os::raw::c_void,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_dispatch_to_thread_async_args(
+        target_thread: pthread_t,
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        satellite: *mut ::std::os::raw::c_void,
+        args: va_list,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_dispatch_to_thread_async_(
+        target_thread: pthread_t,
+        sig: ::std::os::raw::c_uint,
+        func_ptr: *mut ::std::os::raw::c_void,
+        satellite: *mut ::std::os::raw::c_void,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
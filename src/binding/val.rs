@@ -0,0 +1,237 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_HAS_UNBOUND_TYPE_NAMES: u32 = 1;
+pub type emscripten_internal_TYPEID = *const ::std::os::raw::c_void;
+pub const emscripten_internal_EM_INVOKER_KIND_FUNCTION: emscripten_internal_EM_INVOKER_KIND = 0;
+pub const emscripten_internal_EM_INVOKER_KIND_METHOD: emscripten_internal_EM_INVOKER_KIND = 1;
+pub const emscripten_internal_EM_INVOKER_KIND_CONSTRUCTOR: emscripten_internal_EM_INVOKER_KIND = 2;
+pub const emscripten_internal_EM_INVOKER_KIND_CAST: emscripten_internal_EM_INVOKER_KIND = 3;
+pub type emscripten_internal_EM_INVOKER_KIND = ::std::os::raw::c_int;
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_register_symbol"]
+    pub fn emscripten_internal__emval_register_symbol(arg1: *const ::std::os::raw::c_char);
+}
+pub const _EMVAL_UNDEFINED: emscripten_internal__bindgen_ty_1 = 2;
+pub const _EMVAL_NULL: emscripten_internal__bindgen_ty_1 = 4;
+pub const _EMVAL_TRUE: emscripten_internal__bindgen_ty_1 = 6;
+pub const _EMVAL_FALSE: emscripten_internal__bindgen_ty_1 = 8;
+pub const _EMVAL_LAST_RESERVED_HANDLE: emscripten_internal__bindgen_ty_1 = 8;
+pub type emscripten_internal__bindgen_ty_1 = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten_internal__EM_DESTRUCTORS {
+    _unused: [u8; 0],
+}
+pub type emscripten_internal_EM_DESTRUCTORS = *mut emscripten_internal__EM_DESTRUCTORS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten_internal__EM_INVOKER {
+    _unused: [u8; 0],
+}
+pub type emscripten_internal_EM_INVOKER = *mut emscripten_internal__EM_INVOKER;
+pub type emscripten_internal_EM_GENERIC_WIRE_TYPE = f64;
+pub type emscripten_internal_EM_VAR_ARGS = *const ::std::os::raw::c_void;
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_incref"]
+    pub fn emscripten_internal__emval_incref(value: emscripten_EM_VAL);
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_decref"]
+    pub fn emscripten_internal__emval_decref(value: emscripten_EM_VAL);
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_run_destructors"]
+    pub fn emscripten_internal__emval_run_destructors(handle: emscripten_internal_EM_DESTRUCTORS);
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_array"]
+    pub fn emscripten_internal__emval_new_array() -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_array_from_memory_view"]
+    pub fn emscripten_internal__emval_new_array_from_memory_view(
+        mv: emscripten_EM_VAL,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_object"]
+    pub fn emscripten_internal__emval_new_object() -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_cstring"]
+    pub fn emscripten_internal__emval_new_cstring(
+        arg1: *const ::std::os::raw::c_char,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_u8string"]
+    pub fn emscripten_internal__emval_new_u8string(
+        arg1: *const ::std::os::raw::c_char,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_new_u16string"]
+    pub fn emscripten_internal__emval_new_u16string(arg1: *const u16) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_get_global"]
+    pub fn emscripten_internal__emval_get_global(
+        name: *const ::std::os::raw::c_char,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_get_module_property"]
+    pub fn emscripten_internal__emval_get_module_property(
+        name: *const ::std::os::raw::c_char,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_get_property"]
+    pub fn emscripten_internal__emval_get_property(
+        object: emscripten_EM_VAL,
+        key: emscripten_EM_VAL,
+    ) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_set_property"]
+    pub fn emscripten_internal__emval_set_property(
+        object: emscripten_EM_VAL,
+        key: emscripten_EM_VAL,
+        value: emscripten_EM_VAL,
+    );
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_equals"]
+    pub fn emscripten_internal__emval_equals(
+        first: emscripten_EM_VAL,
+        second: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_strictly_equals"]
+    pub fn emscripten_internal__emval_strictly_equals(
+        first: emscripten_EM_VAL,
+        second: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_greater_than"]
+    pub fn emscripten_internal__emval_greater_than(
+        first: emscripten_EM_VAL,
+        second: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_less_than"]
+    pub fn emscripten_internal__emval_less_than(
+        first: emscripten_EM_VAL,
+        second: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_not"]
+    pub fn emscripten_internal__emval_not(object: emscripten_EM_VAL) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_create_invoker"]
+    pub fn emscripten_internal__emval_create_invoker(
+        argCount: ::std::os::raw::c_uint,
+        argTypes: *const emscripten_internal_TYPEID,
+        kind: emscripten_internal_EM_INVOKER_KIND,
+    ) -> emscripten_internal_EM_INVOKER;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_invoke"]
+    pub fn emscripten_internal__emval_invoke(
+        caller: emscripten_internal_EM_INVOKER,
+        handle: emscripten_EM_VAL,
+        methodName: *const ::std::os::raw::c_char,
+        destructors: *mut emscripten_internal_EM_DESTRUCTORS,
+        argv: emscripten_internal_EM_VAR_ARGS,
+    ) -> emscripten_internal_EM_GENERIC_WIRE_TYPE;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_invoke_i64"]
+    pub fn emscripten_internal__emval_invoke_i64(
+        caller: emscripten_internal_EM_INVOKER,
+        handle: emscripten_EM_VAL,
+        methodName: *const ::std::os::raw::c_char,
+        destructors: *mut emscripten_internal_EM_DESTRUCTORS,
+        argv: emscripten_internal_EM_VAR_ARGS,
+    ) -> i64;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_typeof"]
+    pub fn emscripten_internal__emval_typeof(value: emscripten_EM_VAL) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_instanceof"]
+    pub fn emscripten_internal__emval_instanceof(
+        object: emscripten_EM_VAL,
+        constructor: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_is_number"]
+    pub fn emscripten_internal__emval_is_number(object: emscripten_EM_VAL) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_is_string"]
+    pub fn emscripten_internal__emval_is_string(object: emscripten_EM_VAL) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_in"]
+    pub fn emscripten_internal__emval_in(
+        item: emscripten_EM_VAL,
+        object: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_delete"]
+    pub fn emscripten_internal__emval_delete(
+        object: emscripten_EM_VAL,
+        property: emscripten_EM_VAL,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_throw"]
+    pub fn emscripten_internal__emval_throw(object: emscripten_EM_VAL) -> bool;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_await"]
+    pub fn emscripten_internal__emval_await(promise: emscripten_EM_VAL) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_iter_begin"]
+    pub fn emscripten_internal__emval_iter_begin(iterable: emscripten_EM_VAL) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_iter_next"]
+    pub fn emscripten_internal__emval_iter_next(iterator: emscripten_EM_VAL) -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_coro_suspend"]
+    pub fn emscripten_internal__emval_coro_suspend(
+        promise: emscripten_EM_VAL,
+        coro_ptr: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_from_current_cxa_exception"]
+    pub fn emscripten_internal__emval_from_current_cxa_exception() -> emscripten_EM_VAL;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}_emval_coro_make_promise"]
+    pub fn emscripten_internal__emval_coro_make_promise(
+        resolve: *mut emscripten_EM_VAL,
+        reject: *mut emscripten_EM_VAL,
+    ) -> emscripten_EM_VAL;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct emscripten__EM_VAL {
+    _unused: [u8; 0],
+}
+pub type emscripten_EM_VAL = *mut emscripten__EM_VAL;
@@ -0,0 +1,7 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const __EMSCRIPTEN_major__: u32 = 4;
+pub const __EMSCRIPTEN_minor__: u32 = 0;
+pub const __EMSCRIPTEN_tiny__: u32 = 12;
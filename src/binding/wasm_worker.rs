@@ -0,0 +1,204 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_WASM_WORKER_ID_PARENT: u32 = 0;
+pub const EMSCRIPTEN_LOCK_T_STATIC_INITIALIZER: u32 = 0;
+pub type emscripten_async_wait_volatile_callback_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        address: *mut ::std::os::raw::c_void,
+        value: u32,
+        waitResult: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+unsafe extern "C" {
+    pub fn emscripten_malloc_wasm_worker(stackSize: usize) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_create_wasm_worker(
+        stackPlusTLSAddress: *mut ::std::os::raw::c_void,
+        stackPlusTLSSize: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_terminate_wasm_worker(id: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_terminate_all_wasm_workers();
+}
+unsafe extern "C" {
+    pub fn emscripten_current_thread_is_wasm_worker() -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_self_id() -> u32;
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_v(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn()>,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_vi(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+        arg0: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_vii(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<
+            unsafe extern "C" fn(arg1: ::std::os::raw::c_int, arg2: ::std::os::raw::c_int),
+        >,
+        arg0: ::std::os::raw::c_int,
+        arg1: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_viii(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: ::std::os::raw::c_int,
+                arg2: ::std::os::raw::c_int,
+                arg3: ::std::os::raw::c_int,
+            ),
+        >,
+        arg0: ::std::os::raw::c_int,
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_vd(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64)>,
+        arg0: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_vdd(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64, arg2: f64)>,
+        arg0: f64,
+        arg1: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_vddd(
+        id: ::std::os::raw::c_int,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64, arg2: f64, arg3: f64)>,
+        arg0: f64,
+        arg1: f64,
+        arg2: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_post_function_sig(
+        id: ::std::os::raw::c_int,
+        funcPtr: *mut ::std::os::raw::c_void,
+        sig: *const ::std::os::raw::c_char,
+        ...
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_wasm_worker_sleep(nanoseconds: i64);
+}
+unsafe extern "C" {
+    pub fn emscripten_navigator_hardware_concurrency() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_atomics_is_lock_free(
+        byteWidth: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_init(lock: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_wait_acquire(lock: *mut u32, maxWaitNanoseconds: i64) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_waitinf_acquire(lock: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_busyspin_wait_acquire(lock: *mut u32, maxWaitMilliseconds: f64) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_busyspin_waitinf_acquire(lock: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_async_acquire(
+        lock: *mut u32,
+        asyncWaitFinished: emscripten_async_wait_volatile_callback_t,
+        userData: *mut ::std::os::raw::c_void,
+        maxWaitMilliseconds: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_try_acquire(lock: *mut u32) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_lock_release(lock: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_init(sem: *mut u32, num: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_try_acquire(
+        sem: *mut u32,
+        num: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_async_acquire(
+        sem: *mut u32,
+        num: ::std::os::raw::c_int,
+        asyncWaitFinished: emscripten_async_wait_volatile_callback_t,
+        userData: *mut ::std::os::raw::c_void,
+        maxWaitMilliseconds: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_wait_acquire(
+        sem: *mut u32,
+        num: ::std::os::raw::c_int,
+        maxWaitNanoseconds: i64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_waitinf_acquire(
+        sem: *mut u32,
+        num: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_semaphore_release(sem: *mut u32, num: ::std::os::raw::c_int) -> u32;
+}
+unsafe extern "C" {
+    pub fn emscripten_condvar_init(condvar: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_condvar_waitinf(condvar: *mut u32, lock: *mut u32);
+}
+unsafe extern "C" {
+    pub fn emscripten_condvar_wait(
+        condvar: *mut u32,
+        lock: *mut u32,
+        maxWaitNanoseconds: i64,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_condvar_wait_async(
+        condvar: *mut u32,
+        lock: *mut u32,
+        asyncWaitFinished: emscripten_async_wait_callback_t,
+        userData: *mut ::std::os::raw::c_void,
+        maxWaitMilliseconds: f64,
+    ) -> i32;
+}
+unsafe extern "C" {
+    pub fn emscripten_condvar_signal(condvar: *mut u32, numWaitersToSignal: i64);
+}
@@ -0,0 +1,340 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+pub const EMSCRIPTEN_AUDIO_MAIN_THREAD: u32 = 0;
+pub type EMSCRIPTEN_WEBAUDIO_T = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenWebAudioCreateAttributes {
+    pub latencyHint: *const ::std::os::raw::c_char,
+    pub sampleRate: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebAudioCreateAttributes"]
+        [::std::mem::size_of::<EmscriptenWebAudioCreateAttributes>() - 8usize];
+    ["Alignment of EmscriptenWebAudioCreateAttributes"]
+        [::std::mem::align_of::<EmscriptenWebAudioCreateAttributes>() - 4usize];
+    ["Offset of field: EmscriptenWebAudioCreateAttributes::latencyHint"]
+        [::std::mem::offset_of!(EmscriptenWebAudioCreateAttributes, latencyHint) - 0usize];
+    ["Offset of field: EmscriptenWebAudioCreateAttributes::sampleRate"]
+        [::std::mem::offset_of!(EmscriptenWebAudioCreateAttributes, sampleRate) - 4usize];
+};
+impl Default for EmscriptenWebAudioCreateAttributes {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_create_audio_context(
+        options: *const EmscriptenWebAudioCreateAttributes,
+    ) -> EMSCRIPTEN_WEBAUDIO_T;
+}
+pub type AUDIO_CONTEXT_STATE = ::std::os::raw::c_int;
+pub type EmscriptenResumeAudioContextCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        state: AUDIO_CONTEXT_STATE,
+        userData1: *mut ::std::os::raw::c_void,
+    ),
+>;
+unsafe extern "C" {
+    pub fn emscripten_resume_audio_context_async(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        callback: EmscriptenResumeAudioContextCallback,
+        userData1: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_resume_audio_context_sync(audioContext: EMSCRIPTEN_WEBAUDIO_T);
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_context_state(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+    ) -> AUDIO_CONTEXT_STATE;
+}
+pub type EmscriptenStartWebAudioWorkletCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        success: bool,
+        userData2: *mut ::std::os::raw::c_void,
+    ),
+>;
+unsafe extern "C" {
+    pub fn emscripten_destroy_audio_context(audioContext: EMSCRIPTEN_WEBAUDIO_T);
+}
+unsafe extern "C" {
+    pub fn emscripten_destroy_web_audio_node(objectHandle: EMSCRIPTEN_WEBAUDIO_T);
+}
+unsafe extern "C" {
+    pub fn emscripten_start_wasm_audio_worklet_thread_async(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        stackLowestAddress: *mut ::std::os::raw::c_void,
+        stackSize: u32,
+        callback: EmscriptenStartWebAudioWorkletCallback,
+        userData2: *mut ::std::os::raw::c_void,
+    );
+}
+pub type WEBAUDIO_PARAM_AUTOMATION_RATE = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct WebAudioParamDescriptor {
+    pub defaultValue: f32,
+    pub minValue: f32,
+    pub maxValue: f32,
+    pub automationRate: WEBAUDIO_PARAM_AUTOMATION_RATE,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of WebAudioParamDescriptor"][::std::mem::size_of::<WebAudioParamDescriptor>() - 16usize];
+    ["Alignment of WebAudioParamDescriptor"]
+        [::std::mem::align_of::<WebAudioParamDescriptor>() - 4usize];
+    ["Offset of field: WebAudioParamDescriptor::defaultValue"]
+        [::std::mem::offset_of!(WebAudioParamDescriptor, defaultValue) - 0usize];
+    ["Offset of field: WebAudioParamDescriptor::minValue"]
+        [::std::mem::offset_of!(WebAudioParamDescriptor, minValue) - 4usize];
+    ["Offset of field: WebAudioParamDescriptor::maxValue"]
+        [::std::mem::offset_of!(WebAudioParamDescriptor, maxValue) - 8usize];
+    ["Offset of field: WebAudioParamDescriptor::automationRate"]
+        [::std::mem::offset_of!(WebAudioParamDescriptor, automationRate) - 12usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct WebAudioWorkletProcessorCreateOptions {
+    pub name: *const ::std::os::raw::c_char,
+    pub numAudioParams: ::std::os::raw::c_int,
+    pub audioParamDescriptors: *const WebAudioParamDescriptor,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of WebAudioWorkletProcessorCreateOptions"]
+        [::std::mem::size_of::<WebAudioWorkletProcessorCreateOptions>() - 12usize];
+    ["Alignment of WebAudioWorkletProcessorCreateOptions"]
+        [::std::mem::align_of::<WebAudioWorkletProcessorCreateOptions>() - 4usize];
+    ["Offset of field: WebAudioWorkletProcessorCreateOptions::name"]
+        [::std::mem::offset_of!(WebAudioWorkletProcessorCreateOptions, name) - 0usize];
+    ["Offset of field: WebAudioWorkletProcessorCreateOptions::numAudioParams"]
+        [::std::mem::offset_of!(WebAudioWorkletProcessorCreateOptions, numAudioParams) - 4usize];
+    ["Offset of field: WebAudioWorkletProcessorCreateOptions::audioParamDescriptors"][::std::mem::offset_of!(
+        WebAudioWorkletProcessorCreateOptions,
+        audioParamDescriptors
+    ) - 8usize];
+};
+impl Default for WebAudioWorkletProcessorCreateOptions {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type EmscriptenWorkletProcessorCreatedCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        success: bool,
+        userData3: *mut ::std::os::raw::c_void,
+    ),
+>;
+unsafe extern "C" {
+    pub fn emscripten_create_wasm_audio_worklet_processor_async(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        options: *const WebAudioWorkletProcessorCreateOptions,
+        callback: EmscriptenWorkletProcessorCreatedCallback,
+        userData3: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_context_quantum_size(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+    ) -> ::std::os::raw::c_int;
+}
+pub type EMSCRIPTEN_AUDIO_WORKLET_NODE_T = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AudioSampleFrame {
+    pub numberOfChannels: ::std::os::raw::c_int,
+    pub samplesPerChannel: ::std::os::raw::c_int,
+    pub data: *mut f32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of AudioSampleFrame"][::std::mem::size_of::<AudioSampleFrame>() - 12usize];
+    ["Alignment of AudioSampleFrame"][::std::mem::align_of::<AudioSampleFrame>() - 4usize];
+    ["Offset of field: AudioSampleFrame::numberOfChannels"]
+        [::std::mem::offset_of!(AudioSampleFrame, numberOfChannels) - 0usize];
+    ["Offset of field: AudioSampleFrame::samplesPerChannel"]
+        [::std::mem::offset_of!(AudioSampleFrame, samplesPerChannel) - 4usize];
+    ["Offset of field: AudioSampleFrame::data"]
+        [::std::mem::offset_of!(AudioSampleFrame, data) - 8usize];
+};
+impl Default for AudioSampleFrame {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AudioParamFrame {
+    pub length: ::std::os::raw::c_int,
+    pub data: *mut f32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of AudioParamFrame"][::std::mem::size_of::<AudioParamFrame>() - 8usize];
+    ["Alignment of AudioParamFrame"][::std::mem::align_of::<AudioParamFrame>() - 4usize];
+    ["Offset of field: AudioParamFrame::length"]
+        [::std::mem::offset_of!(AudioParamFrame, length) - 0usize];
+    ["Offset of field: AudioParamFrame::data"]
+        [::std::mem::offset_of!(AudioParamFrame, data) - 4usize];
+};
+impl Default for AudioParamFrame {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type EmscriptenWorkletNodeProcessCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        numInputs: ::std::os::raw::c_int,
+        inputs: *const AudioSampleFrame,
+        numOutputs: ::std::os::raw::c_int,
+        outputs: *mut AudioSampleFrame,
+        numParams: ::std::os::raw::c_int,
+        params: *const AudioParamFrame,
+        userData4: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenAudioWorkletNodeCreateOptions {
+    pub numberOfInputs: ::std::os::raw::c_int,
+    pub numberOfOutputs: ::std::os::raw::c_int,
+    pub outputChannelCounts: *mut ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenAudioWorkletNodeCreateOptions"]
+        [::std::mem::size_of::<EmscriptenAudioWorkletNodeCreateOptions>() - 12usize];
+    ["Alignment of EmscriptenAudioWorkletNodeCreateOptions"]
+        [::std::mem::align_of::<EmscriptenAudioWorkletNodeCreateOptions>() - 4usize];
+    ["Offset of field: EmscriptenAudioWorkletNodeCreateOptions::numberOfInputs"]
+        [::std::mem::offset_of!(EmscriptenAudioWorkletNodeCreateOptions, numberOfInputs) - 0usize];
+    ["Offset of field: EmscriptenAudioWorkletNodeCreateOptions::numberOfOutputs"]
+        [::std::mem::offset_of!(EmscriptenAudioWorkletNodeCreateOptions, numberOfOutputs) - 4usize];
+    ["Offset of field: EmscriptenAudioWorkletNodeCreateOptions::outputChannelCounts"][::std::mem::offset_of!(
+        EmscriptenAudioWorkletNodeCreateOptions,
+        outputChannelCounts
+    ) - 8usize];
+};
+impl Default for EmscriptenAudioWorkletNodeCreateOptions {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_create_wasm_audio_worklet_node(
+        audioContext: EMSCRIPTEN_WEBAUDIO_T,
+        name: *const ::std::os::raw::c_char,
+        options: *const EmscriptenAudioWorkletNodeCreateOptions,
+        processCallback: EmscriptenWorkletNodeProcessCallback,
+        userData4: *mut ::std::os::raw::c_void,
+    ) -> EMSCRIPTEN_AUDIO_WORKLET_NODE_T;
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_node_connect(
+        source: EMSCRIPTEN_WEBAUDIO_T,
+        destination: EMSCRIPTEN_WEBAUDIO_T,
+        outputIndex: ::std::os::raw::c_int,
+        inputIndex: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_current_thread_is_audio_worklet() -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_v(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn()>,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_vi(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+        arg0: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_vii(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<
+            unsafe extern "C" fn(arg1: ::std::os::raw::c_int, arg2: ::std::os::raw::c_int),
+        >,
+        arg0: ::std::os::raw::c_int,
+        arg1: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_viii(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: ::std::os::raw::c_int,
+                arg2: ::std::os::raw::c_int,
+                arg3: ::std::os::raw::c_int,
+            ),
+        >,
+        arg0: ::std::os::raw::c_int,
+        arg1: ::std::os::raw::c_int,
+        arg2: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_vd(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64)>,
+        arg0: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_vdd(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64, arg2: f64)>,
+        arg0: f64,
+        arg1: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_vddd(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: ::std::option::Option<unsafe extern "C" fn(arg1: f64, arg2: f64, arg3: f64)>,
+        arg0: f64,
+        arg1: f64,
+        arg2: f64,
+    );
+}
+unsafe extern "C" {
+    pub fn emscripten_audio_worklet_post_function_sig(
+        id: EMSCRIPTEN_WEBAUDIO_T,
+        funcPtr: *mut ::std::os::raw::c_void,
+        sig: *const ::std::os::raw::c_char,
+        ...
+    );
+}
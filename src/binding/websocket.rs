@@ -0,0 +1,270 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use super::*;
+
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_ready_state(
+        socket: ::std::os::raw::c_int,
+        readyState: *mut ::std::os::raw::c_ushort,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_buffered_amount(
+        socket: ::std::os::raw::c_int,
+        bufferedAmount: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_url(
+        socket: ::std::os::raw::c_int,
+        url: *mut ::std::os::raw::c_char,
+        urlLength: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_url_length(
+        socket: ::std::os::raw::c_int,
+        urlLength: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_extensions(
+        socket: ::std::os::raw::c_int,
+        extensions: *mut ::std::os::raw::c_char,
+        extensionsLength: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_extensions_length(
+        socket: ::std::os::raw::c_int,
+        extensionsLength: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_protocol(
+        socket: ::std::os::raw::c_int,
+        protocol: *mut ::std::os::raw::c_char,
+        protocolLength: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_get_protocol_length(
+        socket: ::std::os::raw::c_int,
+        protocolLength: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenWebSocketOpenEvent {
+    pub socket: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebSocketOpenEvent"]
+        [::std::mem::size_of::<EmscriptenWebSocketOpenEvent>() - 4usize];
+    ["Alignment of EmscriptenWebSocketOpenEvent"]
+        [::std::mem::align_of::<EmscriptenWebSocketOpenEvent>() - 4usize];
+    ["Offset of field: EmscriptenWebSocketOpenEvent::socket"]
+        [::std::mem::offset_of!(EmscriptenWebSocketOpenEvent, socket) - 0usize];
+};
+pub type em_websocket_open_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        websocketEvent: *const EmscriptenWebSocketOpenEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_websocket_set_onopen_callback_on_thread(
+        socket: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_websocket_open_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenWebSocketMessageEvent {
+    pub socket: ::std::os::raw::c_int,
+    pub data: *mut u8,
+    pub numBytes: u32,
+    pub isText: bool,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebSocketMessageEvent"]
+        [::std::mem::size_of::<EmscriptenWebSocketMessageEvent>() - 16usize];
+    ["Alignment of EmscriptenWebSocketMessageEvent"]
+        [::std::mem::align_of::<EmscriptenWebSocketMessageEvent>() - 4usize];
+    ["Offset of field: EmscriptenWebSocketMessageEvent::socket"]
+        [::std::mem::offset_of!(EmscriptenWebSocketMessageEvent, socket) - 0usize];
+    ["Offset of field: EmscriptenWebSocketMessageEvent::data"]
+        [::std::mem::offset_of!(EmscriptenWebSocketMessageEvent, data) - 4usize];
+    ["Offset of field: EmscriptenWebSocketMessageEvent::numBytes"]
+        [::std::mem::offset_of!(EmscriptenWebSocketMessageEvent, numBytes) - 8usize];
+    ["Offset of field: EmscriptenWebSocketMessageEvent::isText"]
+        [::std::mem::offset_of!(EmscriptenWebSocketMessageEvent, isText) - 12usize];
+};
+impl Default for EmscriptenWebSocketMessageEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_websocket_message_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        websocketEvent: *const EmscriptenWebSocketMessageEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_websocket_set_onmessage_callback_on_thread(
+        socket: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_websocket_message_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EmscriptenWebSocketErrorEvent {
+    pub socket: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebSocketErrorEvent"]
+        [::std::mem::size_of::<EmscriptenWebSocketErrorEvent>() - 4usize];
+    ["Alignment of EmscriptenWebSocketErrorEvent"]
+        [::std::mem::align_of::<EmscriptenWebSocketErrorEvent>() - 4usize];
+    ["Offset of field: EmscriptenWebSocketErrorEvent::socket"]
+        [::std::mem::offset_of!(EmscriptenWebSocketErrorEvent, socket) - 0usize];
+};
+pub type em_websocket_error_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        websocketEvent: *const EmscriptenWebSocketErrorEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_websocket_set_onerror_callback_on_thread(
+        socket: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_websocket_error_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenWebSocketCloseEvent {
+    pub socket: ::std::os::raw::c_int,
+    pub wasClean: bool,
+    pub code: ::std::os::raw::c_ushort,
+    pub reason: [::std::os::raw::c_char; 512usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebSocketCloseEvent"]
+        [::std::mem::size_of::<EmscriptenWebSocketCloseEvent>() - 520usize];
+    ["Alignment of EmscriptenWebSocketCloseEvent"]
+        [::std::mem::align_of::<EmscriptenWebSocketCloseEvent>() - 4usize];
+    ["Offset of field: EmscriptenWebSocketCloseEvent::socket"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCloseEvent, socket) - 0usize];
+    ["Offset of field: EmscriptenWebSocketCloseEvent::wasClean"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCloseEvent, wasClean) - 4usize];
+    ["Offset of field: EmscriptenWebSocketCloseEvent::code"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCloseEvent, code) - 6usize];
+    ["Offset of field: EmscriptenWebSocketCloseEvent::reason"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCloseEvent, reason) - 8usize];
+};
+impl Default for EmscriptenWebSocketCloseEvent {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type em_websocket_close_callback_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        eventType: ::std::os::raw::c_int,
+        websocketEvent: *const EmscriptenWebSocketCloseEvent,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn emscripten_websocket_set_onclose_callback_on_thread(
+        socket: ::std::os::raw::c_int,
+        userData: *mut ::std::os::raw::c_void,
+        callback: em_websocket_close_callback_func,
+        targetThread: pthread_t,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmscriptenWebSocketCreateAttributes {
+    pub url: *const ::std::os::raw::c_char,
+    pub protocols: *const ::std::os::raw::c_char,
+    pub createOnMainThread: bool,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of EmscriptenWebSocketCreateAttributes"]
+        [::std::mem::size_of::<EmscriptenWebSocketCreateAttributes>() - 12usize];
+    ["Alignment of EmscriptenWebSocketCreateAttributes"]
+        [::std::mem::align_of::<EmscriptenWebSocketCreateAttributes>() - 4usize];
+    ["Offset of field: EmscriptenWebSocketCreateAttributes::url"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCreateAttributes, url) - 0usize];
+    ["Offset of field: EmscriptenWebSocketCreateAttributes::protocols"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCreateAttributes, protocols) - 4usize];
+    ["Offset of field: EmscriptenWebSocketCreateAttributes::createOnMainThread"]
+        [::std::mem::offset_of!(EmscriptenWebSocketCreateAttributes, createOnMainThread) - 8usize];
+};
+impl Default for EmscriptenWebSocketCreateAttributes {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_is_supported() -> bool;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_new(
+        createAttributes: *mut EmscriptenWebSocketCreateAttributes,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_send_utf8_text(
+        socket: ::std::os::raw::c_int,
+        textData: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_send_binary(
+        socket: ::std::os::raw::c_int,
+        binaryData: *mut ::std::os::raw::c_void,
+        dataLength: u32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_close(
+        socket: ::std::os::raw::c_int,
+        code: ::std::os::raw::c_ushort,
+        reason: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_delete(socket: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn emscripten_websocket_deinitialize();
+}
@@ -0,0 +1,145 @@
+//! Typed access to the settings the program was linked with, through
+//! `emscripten_get_compiler_setting`.
+//!
+//! The settings are only kept in the output when linking with `-sRETAIN_COMPILER_SETTINGS`.
+//! Without it every setting is [`CompilerSetting::Unavailable`], instead of the exception the C
+//! function throws.
+
+use crate::*;
+use std::ffi::{CStr, c_char};
+
+mod js {
+    use std::ffi::c_char;
+
+    crate::js! {
+        fn _compiler_setting_rs_retained() -> bool
+        {
+            return typeof getCompilerSetting == "function";
+        }
+
+        fn _compiler_setting_rs_kind(name: *const c_char) -> i32
+        {
+            if (typeof getCompilerSetting != "function") return 0;
+            var value = getCompilerSetting(UTF8ToString(name));
+            if (typeof value == "number" || typeof value == "boolean") return 1;
+            if (Array.isArray(value)) return 2;
+            if (typeof value == "string" && !value.startsWith("invalid compiler setting: ")) return 2;
+            return 0;
+        }
+
+        fn _compiler_setting_rs_number(name: *const c_char) -> f64
+        {
+            // Numbers are read here, as the C function truncates them to a pointer-sized integer.
+            return Number(getCompilerSetting(UTF8ToString(name)));
+        }
+    }
+}
+
+/// The value of a compiler setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompilerSetting {
+    /// A numeric or boolean setting, booleans being 0 or 1.
+    Int(i64),
+    /// A string setting, or a list setting joined with commas.
+    String(String),
+    /// The setting does not exist, or the settings were not retained.
+    Unavailable,
+}
+
+impl CompilerSetting {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        self.as_int().map(|value| value != 0)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the program was linked with `-sRETAIN_COMPILER_SETTINGS`.
+pub fn settings_retained() -> bool {
+    unsafe { js::_compiler_setting_rs_retained() }
+}
+
+/// Reads the compiler setting `name`, e.g. `c"INITIAL_MEMORY"`.
+pub fn compiler_setting(name: &CStr) -> CompilerSetting {
+    match unsafe { js::_compiler_setting_rs_kind(name.as_ptr()) } {
+        1 => CompilerSetting::Int(unsafe { js::_compiler_setting_rs_number(name.as_ptr()) } as i64),
+        2 => {
+            // The string is cached by emscripten and must not be freed.
+            let ptr = unsafe { emscripten_get_compiler_setting(name.as_ptr()) } as *const c_char;
+            let value = unsafe { CStr::from_ptr(ptr) };
+            CompilerSetting::String(value.to_string_lossy().into_owned())
+        }
+        _ => CompilerSetting::Unavailable,
+    }
+}
+
+/// `ALLOW_MEMORY_GROWTH`: whether the wasm memory can grow.
+pub fn allow_memory_growth() -> Option<bool> {
+    compiler_setting(c"ALLOW_MEMORY_GROWTH").as_bool()
+}
+
+/// `PTHREADS`: whether the program was linked with `-pthread`.
+pub fn pthreads() -> Option<bool> {
+    compiler_setting(c"PTHREADS").as_bool()
+}
+
+/// `ASYNCIFY`: whether the program can suspend the wasm stack, either with asyncify or JSPI.
+///
+/// [`crate::asyncify::mode`] tells the same without retained settings.
+pub fn asyncify() -> Option<bool> {
+    compiler_setting(c"ASYNCIFY").as_bool()
+}
+
+/// `WASM_WORKERS`: whether the wasm workers API is available.
+pub fn wasm_workers() -> Option<bool> {
+    compiler_setting(c"WASM_WORKERS").as_bool()
+}
+
+/// `ALLOW_BLOCKING_ON_MAIN_THREAD`: whether the main browser thread may block, e.g. in
+/// `pthread_join`.
+pub fn allow_blocking_on_main_thread() -> Option<bool> {
+    compiler_setting(c"ALLOW_BLOCKING_ON_MAIN_THREAD").as_bool()
+}
+
+/// `MAXIMUM_MEMORY`: the largest size the wasm memory can grow to, in bytes.
+pub fn maximum_memory() -> Option<i64> {
+    compiler_setting(c"MAXIMUM_MEMORY").as_int()
+}
+
+/// `ENVIRONMENT`: the JS environments the program supports, e.g. `web,worker`.
+pub fn environment() -> Option<String> {
+    compiler_setting(c"ENVIRONMENT").as_str().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiler_setting() {
+        assert_eq!(
+            compiler_setting(c"NOT_A_SETTING"),
+            CompilerSetting::Unavailable
+        );
+        if settings_retained() {
+            assert!(allow_memory_growth().is_some());
+            // The default of 2GB does not fit into a 32-bit integer.
+            assert!(maximum_memory().is_some_and(|bytes| bytes >= 1 << 31));
+            assert!(environment().is_some_and(|environment| environment.contains("node")));
+        } else {
+            assert_eq!(allow_memory_growth(), None);
+        }
+    }
+}
@@ -1,3 +1,17 @@
+use std::cell::RefCell;
+use std::ffi::{CStr, c_char, c_void};
+use std::fmt;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::emscripten_builtin_free;
+#[cfg(feature = "pthreads")]
+use crate::{
+    emscripten_is_main_runtime_thread, emscripten_main_runtime_thread_id, emscripten_proxy_async,
+    emscripten_proxy_get_system_queue, emscripten_proxy_sync,
+};
+
 #[macro_export]
 macro_rules! export_bytes {
     ($asm:ident, $exported_symbol:ident, $code:expr, $size_code:expr) => {
@@ -17,44 +31,491 @@ macro_rules! export_bytes {
 #[macro_export]
 macro_rules! export_script_to_linker {
     (
-       $export:ident, $name:ident, $($arg_name:ident)*, $($body:tt)*
+       $export:ident, $name:ident, [$($sig:tt)*], $($body:tt)*
     ) => {
-        $crate::export_bytes!($export, ${concat(__em_js_ref_, $name)}, *b"\0", 1);
-        $crate::export_bytes!($export,
-            ${concat(__em_js__, $name)},
+        emscripten_rs_macros::export_symbol!($export, __em_js_ref_, $name, *b"\0", 1);
+        emscripten_rs_macros::export_lib_deps!($export, $name, [$($sig)*]);
+        emscripten_rs_macros::export_symbol!($export, __em_js__, $name [$($sig)*],
             emscripten_rs_macros::get_decorated_script!((
-                ($($arg_name),*),
+                [$($sig)*],
                 stringify!({ $($body)* })
             )),
             emscripten_rs_macros::len_in_bytes!((
-                ($($arg_name),*),
+                [$($sig)*],
                 stringify!({ $($body)* })
             ))
         );
     };
 }
 
+#[macro_export]
+macro_rules! export_source_to_linker {
+    (
+       $export:ident, $name:ident, [$($sig:tt)*], $source:expr
+    ) => {
+        emscripten_rs_macros::export_symbol!($export, __em_js_ref_, $name, *b"\0", 1);
+        emscripten_rs_macros::export_lib_deps!($export, $name, [$($sig)*]);
+        emscripten_rs_macros::export_symbol!($export, __em_js__, $name [$($sig)*],
+            $crate::em_js::concat_script(&[
+                emscripten_rs_macros::script_prefix!(([$($sig)*], $source)),
+                $source,
+                emscripten_rs_macros::script_suffix!([$($sig)*]),
+            ]),
+            $crate::em_js::script_len(&[
+                emscripten_rs_macros::script_prefix!(([$($sig)*], $source)),
+                $source,
+                emscripten_rs_macros::script_suffix!([$($sig)*]),
+            ])
+        );
+    };
+}
+
+#[doc(hidden)]
+pub const fn script_len(parts: &[&str]) -> usize {
+    let mut len = 1;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Joins the parts into a null terminated byte array at compile time.
+#[doc(hidden)]
+pub const fn concat_script<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut script = [0; N];
+    let mut offset = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            script[offset] = bytes[j];
+            offset += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    script
+}
+
+/// An exception thrown by the body of a `js!` function returning `Result<T, JsError>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsError {
+    message: String,
+    stack: Option<String>,
+}
+
+impl JsError {
+    /// Takes ownership of the message and stack strings allocated by the JS side.
+    #[doc(hidden)]
+    pub unsafe fn from_raw(error: [*mut c_char; 2]) -> Self {
+        let take = |ptr: *mut c_char| {
+            (!ptr.is_null()).then(|| unsafe {
+                let string = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+                emscripten_builtin_free(ptr as _);
+                string
+            })
+        };
+        Self {
+            message: take(error[0]).unwrap_or_default(),
+            stack: take(error[1]),
+        }
+    }
+
+    /// The message of the thrown `Error`, or the thrown value converted to a string.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The stack trace of the thrown `Error`, if it had one.
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JS exception: {}", self.message)
+    }
+}
+
+impl std::error::Error for JsError {}
+
+struct FutureState<T> {
+    result: Option<Result<T, JsError>>,
+    waker: Option<Waker>,
+}
+
+/// The result of an `async fn` defined with `js!`, resolved when the promise of its body settles.
+///
+/// The promise settles from the JS event loop of the calling thread, so the future completes only
+/// once control returns to the browser, e.g. in an executor driven by `requestAnimationFrame` or
+/// `emscripten_set_main_loop`, and never while the thread blocks on it.
+pub struct JsFuture<T> {
+    state: Rc<RefCell<FutureState<T>>>,
+}
+
+impl<T> JsFuture<T> {
+    #[doc(hidden)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(FutureState {
+                result: None,
+                waker: None,
+            })),
+        }
+    }
+
+    /// A reference to the shared state for the JS side, released by [`JsFuture::settle`].
+    #[doc(hidden)]
+    pub fn raw_state(&self) -> *const c_void {
+        Rc::into_raw(self.state.clone()) as _
+    }
+
+    /// Stores the result of the promise and wakes the task waiting on the future.
+    #[doc(hidden)]
+    pub unsafe fn settle(state: *const c_void, result: Result<T, JsError>) {
+        let state = unsafe { Rc::from_raw(state as *const RefCell<FutureState<T>>) };
+        let waker = {
+            let mut state = state.borrow_mut();
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for JsFuture<T> {
+    type Output = Result<T, JsError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Runs `f` on the main runtime thread through the system proxying queue and waits for its result.
+/// Used by `js!` functions marked with `#[proxy(sync)]`.
+#[cfg(feature = "pthreads")]
+#[doc(hidden)]
+pub unsafe fn proxy_sync<F: FnOnce() -> R, R>(f: F) -> R {
+    struct Call<F, R> {
+        f: Option<F>,
+        result: Option<R>,
+    }
+
+    unsafe extern "C" fn run<F: FnOnce() -> R, R>(arg: *mut c_void) {
+        let call = unsafe { &mut *(arg as *mut Call<F, R>) };
+        call.result = call.f.take().map(|f| f());
+    }
+
+    if unsafe { emscripten_is_main_runtime_thread() } != 0 {
+        return f();
+    }
+
+    let mut call = Call {
+        f: Some(f),
+        result: None,
+    };
+    let proxied = unsafe {
+        emscripten_proxy_sync(
+            emscripten_proxy_get_system_queue(),
+            emscripten_main_runtime_thread_id(),
+            Some(run::<F, R>),
+            &mut call as *mut Call<F, R> as *mut c_void,
+        )
+    };
+    assert!(
+        proxied != 0,
+        "failed to proxy a call to the main runtime thread"
+    );
+    call.result
+        .expect("the proxied call did not run on the main runtime thread")
+}
+
+/// Queues `f` on the main runtime thread through the system proxying queue without waiting for it.
+/// Used by `js!` functions marked with `#[proxy(async)]`.
+#[cfg(feature = "pthreads")]
+#[doc(hidden)]
+pub unsafe fn proxy_async<F: FnOnce() + Send + 'static>(f: F) {
+    unsafe extern "C" fn run<F: FnOnce()>(arg: *mut c_void) {
+        let f = unsafe { Box::from_raw(arg as *mut F) };
+        f();
+    }
+
+    if unsafe { emscripten_is_main_runtime_thread() } != 0 {
+        return f();
+    }
+
+    let f = Box::into_raw(Box::new(f));
+    let proxied = unsafe {
+        emscripten_proxy_async(
+            emscripten_proxy_get_system_queue(),
+            emscripten_main_runtime_thread_id(),
+            Some(run::<F>),
+            f as *mut c_void,
+        )
+    };
+    if proxied == 0 {
+        drop(unsafe { Box::from_raw(f) });
+        panic!("failed to proxy a call to the main runtime thread");
+    }
+}
+
+/// Without the `pthreads` feature every call is made on the main runtime thread, so `f` runs
+/// directly.
+#[cfg(not(feature = "pthreads"))]
+#[doc(hidden)]
+pub unsafe fn proxy_sync<F: FnOnce() -> R, R>(f: F) -> R {
+    f()
+}
+
+#[cfg(not(feature = "pthreads"))]
+#[doc(hidden)]
+pub unsafe fn proxy_async<F: FnOnce() + Send + 'static>(f: F) {
+    f()
+}
+
+/// Includes a JS file as the body of a `js!` function, resolved relative to the current file.
+#[macro_export]
+macro_rules! include_js {
+    ($path:literal) => {
+        include_str!($path)
+    };
+}
+
+/// Defines a single `js!` function: `$export` is `global_asm` at item level or `asm` inside a
+/// function body.
+#[cfg(em_js_nightly)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! js_fn {
+    (
+        global_asm, $name:ident, [$($sig:tt)*], { $($body:tt)* }
+    ) => {
+        $crate::export_script_to_linker!(global_asm, $name, [$($sig)*], $($body)*);
+
+        emscripten_rs_macros::js_import!($name, [$($sig)*]);
+    };
+
+    (
+        global_asm, $name:ident, [$($sig:tt)*] = $source:expr
+    ) => {
+        $crate::export_source_to_linker!(global_asm, $name, [$($sig)*], $source);
+
+        emscripten_rs_macros::js_import!($name, [$($sig)*]);
+    };
+
+    (
+        asm, $name:ident, [$($sig:tt)*], { $($body:tt)* }
+    ) => {
+        unsafe {
+            $crate::export_script_to_linker!(asm, $name, [$($sig)*], $($body)*);
+        }
+
+        emscripten_rs_macros::js_import!($name, [$($sig)*]);
+    };
+}
+
+/// Defines a single `js!` function as JS library code in a custom section, without inline assembly.
+#[cfg(not(em_js_nightly))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! js_fn {
+    ($export:ident, $($function:tt)*) => {
+        emscripten_rs_macros::js_library!($($function)*);
+    };
+}
+
 /// Define JS functions in Rust.
 ///
 /// This macro provides a faster alternative to the `emscripten_run_script_*` family of functions.
 ///
+/// Slice parameters (`&[u8]`, `&mut [f32]`, ...) of primitive integers and floats arrive in the JS body
+/// as the matching `TypedArray` view over wasm memory, without copying. The view is created from the
+/// current `wasmMemory.buffer` on every call, but it is detached if the body itself grows the memory.
+///
+/// Closure parameters (`impl FnMut(i32) -> f64`, `Box<dyn FnMut(f32)>`) arrive as callable JS functions.
+/// The closure stays alive until the JS side calls `release()` on the function, so JS code that keeps
+/// a callback around is responsible for releasing it. Closure arguments and results must be plain
+/// FFI values. Calling a closure again from JS while it runs, or releasing it then, throws a JS
+/// exception instead.
+///
+/// With the `js_syntax_check` feature the JS body is parsed at compile time, and syntax errors are
+/// reported at the offending tokens instead of failing at runtime.
+///
+/// The body is written as Rust tokens, so JS that Rust cannot tokenize (multi-character single-quoted
+/// strings, template literals, regex literals, comments, `#private` fields, code relying on newlines)
+/// has to be given as source text instead, either as a string literal or with [`include_js!`]. The text
+/// is embedded byte-for-byte:
+///
+/// ```ignore
+/// js! {
+///     fn greet(name: *const c_char) = r#"
+///         console.log(`hello ${UTF8ToString(name)}`); // template literal
+///     "#;
+///
+///     fn parse(json: *const c_char) -> i32 = include_js!("parse.js");
+/// }
+/// ```
+///
+/// Functions returning `Result<T, JsError>` run their body in a try/catch, and exceptions thrown
+/// by it are returned as [`JsError`] instead of aborting the program.
+///
+/// ```ignore
+/// js! {
+///     fn parse_json(text: *const c_char) -> Result<f64, JsError>
+///     {
+///         return JSON.parse(UTF8ToString(text)).value;
+///     }
+/// }
+/// ```
+///
+/// `async fn` declarations run their body as a JS async function, so it can `await` promises, and
+/// return a [`JsFuture`]-backed future resolved with the value of the promise, without requiring
+/// ASYNCIFY. A rejection panics when polled, unless the function returns `Result<T, JsError>`. The
+/// body outlives the borrow of its arguments, so slices arrive as copies rather than views, and
+/// `&mut` slices are rejected.
+///
+/// ```ignore
+/// js! {
+///     async fn fetch_len(url: *const c_char) -> Result<u32, JsError>
+///     {
+///         const response = await fetch(UTF8ToString(url));
+///         return (await response.arrayBuffer()).byteLength;
+///     }
+/// }
+///
+/// let len = unsafe { fetch_len(c"data.bin".as_ptr()) }.await?;
+/// ```
+///
+/// Functions marked with `#[asyncify]` are asyncify imports, the equivalent of `EM_ASYNC_JS`: the
+/// body runs as a JS async function and the calling wasm code is suspended until its promise
+/// settles, so the result is returned synchronously. With `#[asyncify(sleep)]` the body runs in
+/// `Asyncify.handleSleep` instead, and resumes the caller by calling `wakeUp(value)`. Both need the
+/// program to be linked with `-sASYNCIFY` or `-sJSPI`, which can be checked with
+/// [`has_asyncify`](crate::asyncify::has_asyncify).
+///
+/// ```ignore
+/// js! {
+///     #[asyncify]
+///     fn fetch_status(url: *const c_char) -> u32
+///     {
+///         return (await fetch(UTF8ToString(url))).status;
+///     }
+///
+///     #[asyncify(sleep)]
+///     fn next_frame() -> f64
+///     {
+///         requestAnimationFrame(wakeUp);
+///     }
+/// }
+/// ```
+///
+/// In pthread builds, functions that must run on the main runtime thread, e.g. because they touch
+/// the DOM, can be marked with `#[proxy(sync)]` or `#[proxy(async)]`. Calls from other threads are
+/// then sent through the system proxying queue: `sync` blocks until the result is available, while
+/// `async` returns immediately and thus cannot return a value, borrow slices or take arguments that
+/// are not `Send`, such as raw pointers. Calls made on the main runtime thread run directly, and so
+/// do all calls without the `pthreads` feature.
+///
+/// ```ignore
+/// js! {
+///     #[proxy(sync)]
+///     fn window_width() -> f64
+///     {
+///         return window.innerWidth;
+///     }
+/// }
+/// ```
+///
+/// Closure parameters of proxied functions move to the main runtime thread, so they must be `Send`:
+///
+/// ```compile_fail
+/// use emscripten_rs_sys::js;
+/// use std::rc::Rc;
+///
+/// js! {
+///     #[proxy(sync)]
+///     fn on_resize(callback: impl FnMut())
+///     {
+///         window.addEventListener("resize", () => callback());
+///     }
+/// }
+///
+/// let counter = Rc::new(0);
+/// unsafe { on_resize(move || drop(counter.clone())) };
+/// ```
+///
+/// On nightly toolchains the functions are exported as `EM_JS` symbols, which needs the
+/// `asm_experimental_arch` feature. Stable toolchains store `--js-library` code in the
+/// `emscripten_rs_js` custom section instead, so the program has to be linked with the `emcc-rs`
+/// wrapper of the `emscripten-rs-build` crate, which passes that code to emcc:
+///
+/// ```toml
+/// # .cargo/config.toml
+/// [target.wasm32-unknown-emscripten]
+/// linker = "emcc-rs"
+/// ```
+///
+/// Source text then has to be a string literal or [`include_js!`], since it is read by the proc
+/// macro.
+///
 /// See the documentation for more: <https://emscripten.org/docs/porting/connecting_cpp_and_javascript/Interacting-with-code.html#interacting-with-code-call-javascript-from-native>
 #[macro_export]
 macro_rules! js {
+    () => {};
+
     (
-        $( fn $name:ident ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)? { $($body:tt)* } )*
-    ) => 
+        $(#[$($attr:tt)*])*
+        fn $name:ident ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)? { $($body:tt)* }
+        $($rest:tt)*
+    ) => {
+        $crate::js_fn!(global_asm, $name, [$(#[$($attr)*])* ($($arg_name : $arg_ty),*) $(-> $ret)?], { $($body)* });
+
+        $crate::js! { $($rest)* }
+    };
+
     (
-        $( $crate::export_script_to_linker!(global_asm, $name, $($arg_name)*, $($body)*); )*
+        $(#[$($attr:tt)*])*
+        fn $name:ident ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)? = $source:expr;
+        $($rest:tt)*
+    ) => {
+        $crate::js_fn!(global_asm, $name, [$(#[$($attr)*])* ($($arg_name : $arg_ty),*) $(-> $ret)?] = $source);
 
-        $(
-            #[link(wasm_import_module = "env")]
-            #[allow(dead_code)]
-            unsafe extern "C" {
-                pub unsafe fn $name($( $arg_name : $arg_ty ),*) $(-> $ret)?;
-            }
-        )*
-    )
+        $crate::js! { $($rest)* }
+    };
+
+    (
+        $(#[$($attr:tt)*])*
+        async fn $name:ident ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)? { $($body:tt)* }
+        $($rest:tt)*
+    ) => {
+        $crate::js_fn!(global_asm, $name, [$(#[$($attr)*])* async ($($arg_name : $arg_ty),*) $(-> $ret)?], { $($body)* });
+
+        $crate::js! { $($rest)* }
+    };
+
+    (
+        $(#[$($attr:tt)*])*
+        async fn $name:ident ( $( $arg_name:ident : $arg_ty:ty ),*) $(-> $ret:ty)? = $source:expr;
+        $($rest:tt)*
+    ) => {
+        $crate::js_fn!(global_asm, $name, [$(#[$($attr)*])* async ($($arg_name : $arg_ty),*) $(-> $ret)?] = $source);
+
+        $crate::js! { $($rest)* }
+    };
 }
 
 /// Executes a Javascript snippet inside a Rust function.
@@ -62,9 +523,16 @@ pub use emscripten_rs_macros::inline_js;
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
     use std::ffi::{CStr, c_char, c_int};
+    use std::pin::pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
 
-    use crate::{em_js::inline_js, emscripten_builtin_free};
+    use crate::{
+        em_js::{JsError, JsFuture, inline_js},
+        emscripten_builtin_free,
+    };
 
     js! {
         fn get_string_from_js() -> *mut c_char
@@ -94,6 +562,24 @@ mod tests {
                 throw("strings are not equal")
             }
         }
+
+        fn checked_string_param(url: *const c_char) -> Result<(), JsError>
+        {
+            if (UTF8ToString(url) != "test")
+            {
+                throw("strings are not equal")
+            }
+        }
+
+        fn checked_parse(text: *const c_char) -> Result<f64, JsError>
+        {
+            return JSON.parse(UTF8ToString(text)).value;
+        }
+
+        fn checked_bigint(text: *const c_char) -> Result<i64, JsError>
+        {
+            return BigInt(UTF8ToString(text));
+        }
     }
 
     #[test]
@@ -103,6 +589,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_result() {
+        assert_eq!(unsafe { checked_string_param(c"test".as_ptr()) }, Ok(()));
+
+        let error = unsafe { checked_string_param(c"other".as_ptr()) }.unwrap_err();
+        assert_eq!(error.message(), "strings are not equal");
+        assert_eq!(error.stack(), None);
+
+        assert_eq!(
+            unsafe { checked_parse(c"{\"value\": 1.5}".as_ptr()) },
+            Ok(1.5)
+        );
+        let error = unsafe { checked_parse(c"{".as_ptr()) }.unwrap_err();
+        assert!(error.to_string().starts_with("JS exception: "));
+        assert!(
+            error
+                .stack()
+                .is_some_and(|stack| stack.contains("SyntaxError"))
+        );
+
+        assert_eq!(
+            unsafe { checked_bigint(c"-9007199254740993".as_ptr()) },
+            Ok(-9007199254740993)
+        );
+        let error = unsafe { checked_bigint(c"1.5".as_ptr()) }.unwrap_err();
+        assert!(error.message().contains("BigInt"));
+    }
+
     js! {
         fn sum(n: c_int) -> c_int
         {
@@ -124,8 +638,8 @@ mod tests {
         fn f(x: f32) -> f32
         {
             return Math.pow(x, h());
-        } 
-        
+        }
+
         fn g(x: f32) -> f32
         {
             return Math.sqrt(f(x));
@@ -144,15 +658,25 @@ mod tests {
         }
     }
 
+    #[cfg(em_js_nightly)]
     use std::simd::i32x4;
+    #[cfg(em_js_nightly)]
     use std::simd::num::SimdInt;
 
+    #[cfg(em_js_nightly)]
     #[unsafe(no_mangle)]
     #[target_feature(enable = "simd128")]
     pub extern "C" fn hadd_rs(v1: i32, v2: i32, v3: i32, v4: i32) -> i32 {
         i32x4::from_array([v1, v2, v3, v4]).reduce_sum()
     }
 
+    // `portable_simd` is unstable, stable toolchains add up the lanes themselves.
+    #[cfg(not(em_js_nightly))]
+    #[unsafe(no_mangle)]
+    pub extern "C" fn hadd_rs(v1: i32, v2: i32, v3: i32, v4: i32) -> i32 {
+        v1 + v2 + v3 + v4
+    }
+
     js! {
         fn second_js(param: i32) -> i32
         {
@@ -182,6 +706,254 @@ mod tests {
         assert_eq!(unsafe { multiple_params(3, 4, 5) }, 23);
     }
 
+    js! {
+        fn sum_bytes(bytes: &[u8]) -> u32
+        {
+            return bytes.reduce((acc, b) => acc + b, 0);
+        }
+
+        fn scale(samples: &mut [f32], factor: f32)
+        {
+            for (let i = 0; i < samples.length; i++)
+            {
+                samples[i] *= factor;
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice_params() {
+        assert_eq!(unsafe { sum_bytes(&[1, 2, 3, 250]) }, 256);
+
+        let mut samples = [0.5f32, -1.0, 2.0];
+        unsafe { scale(&mut samples, 2.0) };
+        assert_eq!(samples, [1.0, -2.0, 4.0]);
+
+        let bytes = [7u8; 4];
+        let slice = &bytes[..];
+        assert_eq!(
+            inline_js! {
+                (slice: &[u8]) -> u32,
+                return slice.length + slice[0];
+            },
+            11
+        );
+    }
+
+    js! {
+        fn call_twice(callback: impl FnMut(i32) -> i32) -> i32
+        {
+            var result = callback(1) + callback(2);
+            callback.release();
+            return result;
+        }
+
+        fn store_callback(callback: Box<dyn FnMut(f64)>)
+        {
+            globalThis.storedCallback = callback;
+        }
+
+        fn store_reentrant(callback: Box<dyn FnMut(i32) -> i32>)
+        {
+            globalThis.reentrant = callback;
+        }
+    }
+
+    #[test]
+    fn test_closure_params() {
+        let counter = Rc::new(Cell::new(0));
+
+        let calls = counter.clone();
+        let result = unsafe {
+            call_twice(move |x| {
+                calls.set(calls.get() + 1);
+                x * 10
+            })
+        };
+        assert_eq!(result, 30);
+        assert_eq!(counter.get(), 2);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let total = Rc::new(Cell::new(0.0));
+        let sum = total.clone();
+        unsafe { store_callback(Box::new(move |x| sum.set(sum.get() + x))) };
+        inline_js! {
+            globalThis.storedCallback(1.5);
+            globalThis.storedCallback(2.5);
+            globalThis.storedCallback.release();
+        };
+        assert_eq!(total.get(), 4.0);
+        assert_eq!(Rc::strong_count(&total), 1);
+    }
+
+    #[test]
+    fn test_reentrant_closure() {
+        let callback = |depth| {
+            if depth > 0 {
+                return depth;
+            }
+            inline_js! {
+                () -> i32,
+                try {
+                    globalThis.reentrant(1);
+                    return 0;
+                } catch (e) {
+                    return -1;
+                }
+            }
+        };
+        unsafe { store_reentrant(Box::new(callback)) };
+        assert_eq!(
+            inline_js! { () -> i32, return globalThis.reentrant(0); },
+            -1
+        );
+        inline_js! { globalThis.reentrant.release(); };
+    }
+
+    js! {
+        fn raw_source(text: *const c_char) -> i32 = r#"
+            // Neither the comment nor the regex or template literal survive Rust tokenization
+            const match = /^(\d+)-(\d+)$/.exec(UTF8ToString(text));
+            return Number(`${match[1]}${match[2]}`)
+        "#;
+
+        fn included_source(a: i32, b: i32) -> i32 = include_js!("test_data/add.js");
+    }
+
+    #[test]
+    fn test_source_text() {
+        assert_eq!(unsafe { raw_source(c"12-34".as_ptr()) }, 1234);
+        assert_eq!(unsafe { included_source(2, 3) }, 5);
+    }
+
+    #[cfg(feature = "pthreads")]
+    js! {
+        #[proxy(sync)]
+        fn proxied_sum(values: &[i32]) -> i32
+        {
+            return values.reduce((acc, value) => acc + value, 0);
+        }
+
+        #[proxy(async)]
+        fn proxied_store(value: f64)
+        {
+            globalThis.proxiedValue = value;
+        }
+    }
+
+    #[cfg(feature = "pthreads")]
+    #[test]
+    fn test_proxied() {
+        assert_eq!(unsafe { proxied_sum(&[1, 2, 3]) }, 6);
+
+        // The test runs on the main runtime thread, so the async call has completed on return.
+        unsafe { proxied_store(2.5) };
+        let value = inline_js! {
+            () -> f64,
+            return globalThis.proxiedValue;
+        };
+        assert_eq!(value, 2.5);
+    }
+
+    #[cfg(feature = "pthreads")]
+    #[test]
+    fn test_proxied_from_thread() {
+        // Joining waits on a futex, which runs the system queue of the main runtime thread.
+        let sum = std::thread::spawn(|| unsafe { proxied_sum(&[4, 5, 6]) })
+            .join()
+            .unwrap();
+        assert_eq!(sum, 15);
+    }
+
+    js! {
+        async fn delayed_sum(a: i32, b: i32) -> i32
+        {
+            return await Promise.resolve(a + b);
+        }
+
+        async fn rejected() -> Result<(), JsError>
+        {
+            await null;
+            throw new Error("rejected");
+        }
+    }
+
+    js! {
+        async fn async_sum_bytes(bytes: &[u8]) -> u32
+        {
+            await null;
+            return bytes.reduce((acc, b) => acc + b, 0);
+        }
+    }
+
+    /// Polls `future` until it completes, sleeping in between so that the event loop can settle
+    /// the promises.
+    #[cfg(feature = "asyncify")]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            crate::asyncify::sleep(std::time::Duration::ZERO).unwrap();
+        }
+    }
+
+    #[cfg(feature = "asyncify")]
+    #[test]
+    fn test_await_promises() {
+        assert_eq!(block_on(unsafe { delayed_sum(2, 3) }), 5);
+        let error = block_on(unsafe { rejected() }).unwrap_err();
+        assert_eq!(error.message(), "rejected");
+
+        // The bytes are copied by the call, so they can be overwritten before the body reads them.
+        let mut bytes = vec![1, 2, 3];
+        let sum = unsafe { async_sum_bytes(&bytes) };
+        bytes.fill(0);
+        assert_eq!(block_on(sum), 6);
+    }
+
+    #[test]
+    fn test_async() {
+        let mut cx = Context::from_waker(Waker::noop());
+
+        // Promises settle from the event loop, which cannot run while the test blocks it.
+        let mut sum = pin!(unsafe { delayed_sum(2, 3) });
+        assert!(sum.as_mut().poll(&mut cx).is_pending());
+        let mut error = pin!(unsafe { rejected() });
+        assert!(error.as_mut().poll(&mut cx).is_pending());
+
+        let mut future = pin!(JsFuture::<i32>::new());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        unsafe { JsFuture::settle(future.raw_state(), Ok(5)) };
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(Ok(5)));
+    }
+
+    js! {
+        #[asyncify]
+        fn asyncify_sum(a: i32, b: i32) -> i32
+        {
+            return await Promise.resolve(a + b);
+        }
+
+        #[asyncify(sleep)]
+        fn asyncify_timeout(ms: u32) -> u32
+        {
+            setTimeout(() => wakeUp(ms), ms);
+        }
+    }
+
+    #[cfg(feature = "asyncify")]
+    #[test]
+    fn test_asyncify() {
+        assert_eq!(unsafe { asyncify_sum(2, 3) }, 5);
+
+        let start = unsafe { crate::emscripten_get_now() };
+        assert_eq!(unsafe { asyncify_timeout(20) }, 20);
+        assert!(unsafe { crate::emscripten_get_now() } - start >= 20.0);
+    }
+
     #[test]
     fn test_inline_js() {
         assert!(inline_js! {
@@ -223,4 +995,15 @@ mod tests {
             console.log("Home Directory: " + os.homedir);
         };
     }
+
+    macro_rules! forty_two {
+        () => {
+            inline_js! { () -> i32, return 42; }
+        };
+    }
+
+    #[test]
+    fn test_inline_js_same_call_site() {
+        assert_eq!(forty_two!() + forty_two!(), 84);
+    }
 }
@@ -0,0 +1,562 @@
+//! Conversions between Rust values and JS values held through embind's `emval` handles.
+
+use crate::*;
+use std::{
+    collections::HashMap,
+    error::Error,
+    ffi::{CStr, c_char},
+    fmt,
+    hash::BuildHasher,
+};
+
+pub use emscripten_rs_macros::{FromJs, ToJs};
+
+#[link(name = "embind")]
+unsafe extern "C" {}
+
+mod js {
+    use crate::emscripten_EM_VAL;
+    use std::ffi::c_char;
+
+    crate::js! {
+        fn _emval_rs_from_number(value: f64) -> emscripten_EM_VAL
+        {
+            return Emval.toHandle(value);
+        }
+
+        fn _emval_rs_as_number(handle: emscripten_EM_VAL) -> f64
+        {
+            return Emval.toValue(handle);
+        }
+
+        fn _emval_rs_from_utf8(ptr: *const u8, len: usize) -> emscripten_EM_VAL
+        {
+            // `UTF8ToString` stops at the first NUL, `TextDecoder` keeps it. `slice` copies, as
+            // views of a shared memory cannot be decoded.
+            return Emval.toHandle(new TextDecoder().decode(HEAPU8.slice(ptr, ptr + len)));
+        }
+
+        fn _emval_rs_as_string(handle: emscripten_EM_VAL, len: *mut u32) -> *mut c_char
+        {
+            var value = Emval.toValue(handle);
+            var length = lengthBytesUTF8(value);
+            var ptr = _malloc(length + 1);
+            stringToUTF8(value, ptr, length + 1);
+            HEAPU32[len >> 2] = length;
+            return ptr;
+        }
+
+        fn _emval_rs_as_bigint(handle: emscripten_EM_VAL, signed: bool, bits: *mut u64) -> i32
+        {
+            // 0 for other values, 1 when the value was stored, 2 when it is out of range.
+            var value = Emval.toValue(handle);
+            if (typeof value != "bigint") return 0;
+            if ((signed ? BigInt.asIntN(64, value) : BigInt.asUintN(64, value)) != value) return 2;
+            HEAP64[bits >> 3] = BigInt.asIntN(64, value);
+            return 1;
+        }
+
+        fn _emval_rs_keys(handle: emscripten_EM_VAL) -> emscripten_EM_VAL
+        {
+            return Emval.toHandle(Object.keys(Emval.toValue(handle)));
+        }
+
+        fn _emval_rs_typed_array(kind: i32, ptr: *const u8, len: usize) -> emscripten_EM_VAL
+        {
+            var ctor = [
+                Int8Array, Uint8Array, Int16Array, Uint16Array, Int32Array,
+                Uint32Array, BigInt64Array, BigUint64Array, Float32Array, Float64Array
+            ][kind];
+            return Emval.toHandle(new ctor(wasmMemory.buffer, ptr, len));
+        }
+    }
+}
+
+/// An owned reference to a JS value.
+///
+/// Cloning increments the reference count of the underlying handle, dropping decrements it.
+pub struct Val(emscripten_EM_VAL);
+
+impl Val {
+    /// Takes ownership of a raw handle.
+    ///
+    /// # Safety
+    /// The handle must be valid and the caller must own one reference to it.
+    pub unsafe fn from_handle(handle: emscripten_EM_VAL) -> Self {
+        Self(handle)
+    }
+
+    pub fn as_handle(&self) -> emscripten_EM_VAL {
+        self.0
+    }
+
+    /// Releases ownership of the handle without decrementing its reference count.
+    pub fn into_handle(self) -> emscripten_EM_VAL {
+        let handle = self.0;
+        std::mem::forget(self);
+        handle
+    }
+
+    pub fn undefined() -> Self {
+        Self(_EMVAL_UNDEFINED as _)
+    }
+
+    pub fn null() -> Self {
+        Self(_EMVAL_NULL as _)
+    }
+
+    pub fn bool(value: bool) -> Self {
+        Self(if value { _EMVAL_TRUE } else { _EMVAL_FALSE } as _)
+    }
+
+    /// Creates an empty JS object.
+    pub fn object() -> Self {
+        unsafe { Self(emscripten_internal__emval_new_object()) }
+    }
+
+    /// Creates an empty JS array.
+    pub fn array() -> Self {
+        unsafe { Self(emscripten_internal__emval_new_array()) }
+    }
+
+    /// Looks up a property of `globalThis`.
+    pub fn global(name: &CStr) -> Self {
+        unsafe { Self(emscripten_internal__emval_get_global(name.as_ptr())) }
+    }
+
+    /// Looks up a property of the emscripten `Module` object.
+    pub fn module_property(name: &CStr) -> Self {
+        unsafe {
+            Self(emscripten_internal__emval_get_module_property(
+                name.as_ptr(),
+            ))
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Self {
+        self.get_val(&key.to_js())
+    }
+
+    pub fn set(&self, key: &str, value: &Val) {
+        self.set_val(&key.to_js(), value)
+    }
+
+    pub fn get_index(&self, index: u32) -> Self {
+        self.get_val(&index.to_js())
+    }
+
+    pub fn set_index(&self, index: u32, value: &Val) {
+        self.set_val(&index.to_js(), value)
+    }
+
+    pub fn get_val(&self, key: &Val) -> Self {
+        unsafe { Self(emscripten_internal__emval_get_property(self.0, key.0)) }
+    }
+
+    pub fn set_val(&self, key: &Val, value: &Val) {
+        unsafe { emscripten_internal__emval_set_property(self.0, key.0, value.0) }
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        self.0 as usize == _EMVAL_UNDEFINED as usize
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.0 as usize == _EMVAL_NULL as usize
+    }
+
+    pub fn is_number(&self) -> bool {
+        unsafe { emscripten_internal__emval_is_number(self.0) }
+    }
+
+    pub fn is_string(&self) -> bool {
+        unsafe { emscripten_internal__emval_is_string(self.0) }
+    }
+
+    /// The `===` operator.
+    pub fn strictly_equals(&self, other: &Val) -> bool {
+        unsafe { emscripten_internal__emval_strictly_equals(self.0, other.0) }
+    }
+
+    fn as_number(&self) -> Result<f64, FromJsError> {
+        if self.is_number() {
+            Ok(unsafe { js::_emval_rs_as_number(self.0) })
+        } else {
+            Err(FromJsError::new("number"))
+        }
+    }
+
+    /// The bits of a BigInt that fits into a 64-bit integer, `None` if the value is not a BigInt.
+    fn as_bigint_bits(&self, signed: bool) -> Result<Option<u64>, FromJsError> {
+        let mut bits = 0;
+        match unsafe { js::_emval_rs_as_bigint(self.0, signed, &mut bits) } {
+            0 => Ok(None),
+            1 => Ok(Some(bits)),
+            _ => Err(FromJsError::new("bigint representable as a 64-bit integer")),
+        }
+    }
+
+    fn len(&self) -> Result<u32, FromJsError> {
+        u32::from_js(&self.get("length")).map_err(|_| FromJsError::new("array"))
+    }
+}
+
+impl Clone for Val {
+    fn clone(&self) -> Self {
+        unsafe { emscripten_internal__emval_incref(self.0) };
+        Self(self.0)
+    }
+}
+
+impl Drop for Val {
+    fn drop(&mut self) {
+        unsafe { emscripten_internal__emval_decref(self.0) }
+    }
+}
+
+impl fmt::Debug for Val {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Val").field(&self.0).finish()
+    }
+}
+
+/// The JS value did not have the shape required by [`FromJs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromJsError {
+    expected: &'static str,
+}
+
+impl FromJsError {
+    pub fn new(expected: &'static str) -> Self {
+        Self { expected }
+    }
+
+    /// Describes the JS value that was expected.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+}
+
+impl fmt::Display for FromJsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a JS {}", self.expected)
+    }
+}
+
+impl Error for FromJsError {}
+
+/// Converts a Rust value into a JS value.
+pub trait ToJs {
+    fn to_js(&self) -> Val;
+}
+
+/// Converts a JS value into a Rust value.
+pub trait FromJs: Sized {
+    fn from_js(val: &Val) -> Result<Self, FromJsError>;
+}
+
+impl ToJs for Val {
+    fn to_js(&self) -> Val {
+        self.clone()
+    }
+}
+
+impl FromJs for Val {
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        Ok(val.clone())
+    }
+}
+
+impl<T: ToJs + ?Sized> ToJs for &T {
+    fn to_js(&self) -> Val {
+        (**self).to_js()
+    }
+}
+
+impl ToJs for () {
+    fn to_js(&self) -> Val {
+        Val::undefined()
+    }
+}
+
+impl ToJs for bool {
+    fn to_js(&self) -> Val {
+        Val::bool(*self)
+    }
+}
+
+impl FromJs for bool {
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        if val.strictly_equals(&Val::bool(true)) {
+            Ok(true)
+        } else if val.strictly_equals(&Val::bool(false)) {
+            Ok(false)
+        } else {
+            Err(FromJsError::new("boolean"))
+        }
+    }
+}
+
+// Integers travel as JS numbers, so 64-bit values beyond 2^53 lose precision. 64-bit integers are
+// also read from BigInts, e.g. the elements of a `BigInt64Array`.
+macro_rules! impl_integer {
+    ($($ty:ty)*) => {
+        $(
+            impl ToJs for $ty {
+                fn to_js(&self) -> Val {
+                    unsafe { Val(js::_emval_rs_from_number(*self as f64)) }
+                }
+            }
+
+            impl FromJs for $ty {
+                fn from_js(val: &Val) -> Result<Self, FromJsError> {
+                    if <$ty>::BITS == 64
+                        && let Some(bits) = val.as_bigint_bits(<$ty>::MIN != 0)?
+                    {
+                        return Ok(bits as $ty);
+                    }
+                    let number = val.as_number()?;
+                    // `MAX as f64` rounds up to 2^64 for `u64`, so compare with the exclusive bound.
+                    let bits = <$ty>::BITS as i32 - (<$ty>::MIN != 0) as i32;
+                    if number.fract() == 0.0 && number >= <$ty>::MIN as f64 && number < 2f64.powi(bits) {
+                        Ok(number as $ty)
+                    } else {
+                        Err(FromJsError::new(concat!("number representable as ", stringify!($ty))))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8 u8 i16 u16 i32 u32 i64 u64 isize usize);
+
+macro_rules! impl_float {
+    ($($ty:ty)*) => {
+        $(
+            impl ToJs for $ty {
+                fn to_js(&self) -> Val {
+                    unsafe { Val(js::_emval_rs_from_number(*self as f64)) }
+                }
+            }
+
+            impl FromJs for $ty {
+                fn from_js(val: &Val) -> Result<Self, FromJsError> {
+                    val.as_number().map(|number| number as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32 f64);
+
+impl ToJs for str {
+    /// Strings are passed with their length, so interior NULs are kept.
+    fn to_js(&self) -> Val {
+        unsafe { Val(js::_emval_rs_from_utf8(self.as_ptr(), self.len())) }
+    }
+}
+
+impl ToJs for String {
+    fn to_js(&self) -> Val {
+        self.as_str().to_js()
+    }
+}
+
+impl FromJs for String {
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        if !val.is_string() {
+            return Err(FromJsError::new("string"));
+        }
+        unsafe {
+            let mut len = 0;
+            let ptr: *mut c_char = js::_emval_rs_as_string(val.0, &mut len);
+            let bytes = std::slice::from_raw_parts(ptr as *const u8, len as usize);
+            let string = String::from_utf8_lossy(bytes).into_owned();
+            emscripten_builtin_free(ptr as _);
+            Ok(string)
+        }
+    }
+}
+
+impl<T: ToJs> ToJs for Option<T> {
+    fn to_js(&self) -> Val {
+        match self {
+            Some(value) => value.to_js(),
+            None => Val::null(),
+        }
+    }
+}
+
+impl<T: FromJs> FromJs for Option<T> {
+    /// Both `null` and `undefined` map to `None`.
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        if val.is_null() || val.is_undefined() {
+            Ok(None)
+        } else {
+            T::from_js(val).map(Some)
+        }
+    }
+}
+
+impl<T: ToJs> ToJs for Vec<T> {
+    fn to_js(&self) -> Val {
+        let array = Val::array();
+        for (index, item) in self.iter().enumerate() {
+            array.set_index(index as u32, &item.to_js());
+        }
+        array
+    }
+}
+
+impl<T: FromJs> FromJs for Vec<T> {
+    /// Accepts arrays, typed arrays and other array-like objects.
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        (0..val.len()?)
+            .map(|index| T::from_js(&val.get_index(index)))
+            .collect()
+    }
+}
+
+impl<T: ToJs, S: BuildHasher> ToJs for HashMap<String, T, S> {
+    fn to_js(&self) -> Val {
+        let object = Val::object();
+        for (key, value) in self {
+            object.set(key, &value.to_js());
+        }
+        object
+    }
+}
+
+impl<T: FromJs, S: BuildHasher + Default> FromJs for HashMap<String, T, S> {
+    /// Reads the own enumerable properties of a JS object.
+    fn from_js(val: &Val) -> Result<Self, FromJsError> {
+        if val.is_null() || val.is_undefined() {
+            return Err(FromJsError::new("object"));
+        }
+        let keys = unsafe { Val(js::_emval_rs_keys(val.0)) };
+        (0..keys.len()?)
+            .map(|index| {
+                let key = keys.get_index(index);
+                Ok((String::from_js(&key)?, T::from_js(&val.get_val(&key))?))
+            })
+            .collect()
+    }
+}
+
+/// Element types that have a matching JS `TypedArray`.
+pub trait TypedArrayElement: Copy {
+    #[doc(hidden)]
+    const KIND: i32;
+}
+
+macro_rules! impl_typed_array_element {
+    ($($ty:ty => $kind:literal),*) => {
+        $( impl TypedArrayElement for $ty { const KIND: i32 = $kind; } )*
+    };
+}
+
+impl_typed_array_element!(
+    i8 => 0, u8 => 1, i16 => 2, u16 => 3, i32 => 4,
+    u32 => 5, i64 => 6, u64 => 7, f32 => 8, f64 => 9
+);
+
+impl<T: TypedArrayElement> ToJs for [T] {
+    /// Creates a typed array view over wasm memory without copying.
+    ///
+    /// The view is only valid while the slice is borrowed and until the wasm memory grows.
+    fn to_js(&self) -> Val {
+        unsafe {
+            Val(js::_emval_rs_typed_array(
+                T::KIND,
+                self.as_ptr() as _,
+                self.len(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(ToJs, FromJs, Debug, PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+        label: Option<String>,
+    }
+
+    #[derive(ToJs, FromJs, Debug, PartialEq)]
+    struct Tagged<T> {
+        r#type: String,
+        value: T,
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(i32::from_js(&42.to_js()), Ok(42));
+        assert_eq!(f32::from_js(&1.5f32.to_js()), Ok(1.5));
+        assert_eq!(bool::from_js(&true.to_js()), Ok(true));
+        assert!(u8::from_js(&300.to_js()).is_err());
+        assert!(i32::from_js(&"42".to_js()).is_err());
+        assert!(u64::from_js(&2f64.powi(64).to_js()).is_err());
+        assert!(i64::from_js(&2f64.powi(63).to_js()).is_err());
+        assert_eq!(i64::from_js(&(-2f64).powi(63).to_js()), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn test_interior_nul() {
+        let string = "before\0after";
+        assert_eq!(String::from_js(&string.to_js()), Ok(string.to_string()));
+    }
+
+    #[test]
+    fn test_collections() {
+        let strings = vec!["Árvíztűrő".to_string(), "tükörfúrógép".to_string()];
+        assert_eq!(Vec::<String>::from_js(&strings.to_js()), Ok(strings));
+
+        let map = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+        assert_eq!(HashMap::<String, i32>::from_js(&map.to_js()), Ok(map));
+
+        assert_eq!(Option::<i32>::from_js(&Val::undefined()), Ok(None));
+    }
+
+    #[test]
+    fn test_typed_array() {
+        let samples = [0.25f32, 0.5, 0.75];
+        let view = samples.as_slice().to_js();
+        assert_eq!(Vec::<f32>::from_js(&view), Ok(samples.to_vec()));
+
+        let longs = [i64::MIN, -1, i64::MAX];
+        let view = longs.as_slice().to_js();
+        assert_eq!(Vec::<i64>::from_js(&view), Ok(longs.to_vec()));
+        assert!(Vec::<u64>::from_js(&view).is_err());
+        let unsigned = [u64::MAX, 0];
+        assert_eq!(
+            Vec::<u64>::from_js(&unsigned.as_slice().to_js()),
+            Ok(unsigned.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_derive() {
+        let point = Point {
+            x: 1.0,
+            y: -2.0,
+            label: Some("origin".to_string()),
+        };
+        let val = point.to_js();
+        assert_eq!(f64::from_js(&val.get("y")), Ok(-2.0));
+        assert_eq!(Point::from_js(&val), Ok(point));
+
+        let tagged = Tagged {
+            r#type: "count".to_string(),
+            value: 3u32,
+        };
+        let val = tagged.to_js();
+        assert_eq!(String::from_js(&val.get("type")), Ok("count".to_string()));
+        assert_eq!(Tagged::from_js(&val), Ok(tagged));
+    }
+}
@@ -1,16 +1,58 @@
-#![cfg_attr(
-    feature = "em_js",
-    feature(asm_experimental_arch, macro_metavar_expr_concat)
-)]
-#![cfg_attr(all(feature = "em_js", test), feature(portable_simd))]
+#![cfg_attr(all(feature = "em_js", em_js_nightly), feature(asm_experimental_arch))]
+#![cfg_attr(all(feature = "em_js", em_js_nightly, test), feature(portable_simd))]
 #![allow(clippy::approx_constant)]
 
+extern crate self as emscripten_rs_sys;
+
 pub mod binding;
 pub use binding::*;
 
+pub mod asyncify;
+
+#[cfg(any(feature = "pthreads", feature = "wasm_workers"))]
+pub mod atomic_wait;
+
+#[cfg(feature = "audio_worklet")]
+pub mod audio_ring_buffer;
+
+#[cfg(feature = "audio_worklet")]
+pub mod audio_worklet;
+
+#[cfg_attr(
+    not(any(feature = "pthreads", feature = "wasm_workers")),
+    allow(dead_code)
+)]
+mod oneshot;
+
+#[cfg(feature = "pthreads")]
+pub mod proxying_queue;
+
+#[cfg(feature = "wasm_workers")]
+pub mod sync;
+
+#[cfg(feature = "wasm_workers")]
+pub mod workers;
+
+// The link settings of the enabled API features, for the `emcc-rs` linker of emscripten-rs-build.
+include!(concat!(env!("OUT_DIR"), "/link_args.rs"));
+
+// The websocket API is implemented in a JS library that emcc only links on request.
+#[cfg(feature = "websocket")]
+#[link(name = "websocket.js")]
+unsafe extern "C" {}
+
+#[cfg(feature = "em_js")]
+pub mod asm;
+
+#[cfg(feature = "em_js")]
+pub mod compiler_setting;
+
 #[cfg(feature = "em_js")]
 pub mod em_js;
 
+#[cfg(feature = "em_js")]
+pub mod emval;
+
 #[cfg(test)]
 mod unit_test;
 
@@ -18,3 +60,11 @@ pub const EM_CALLBACK_THREAD_CONTEXT_MAIN_RUNTIME_THREAD: pthread_t = 1 as _;
 pub const EM_CALLBACK_THREAD_CONTEXT_CALLING_THREAD: pthread_t = 2 as _;
 pub const EM_CALLBACK_THREAD_CONTEXT_MAIN_BROWSER_THREAD: pthread_t =
     EM_CALLBACK_THREAD_CONTEXT_MAIN_RUNTIME_THREAD;
+
+pub const EMSCRIPTEN_EVENT_TARGET_DOCUMENT: *const std::ffi::c_char = 1 as _;
+pub const EMSCRIPTEN_EVENT_TARGET_WINDOW: *const std::ffi::c_char = 2 as _;
+pub const EMSCRIPTEN_EVENT_TARGET_SCREEN: *const std::ffi::c_char = 3 as _;
+
+pub const ATOMICS_WAIT_OK: std::ffi::c_int = 0;
+pub const ATOMICS_WAIT_NOT_EQUAL: std::ffi::c_int = 1;
+pub const ATOMICS_WAIT_TIMED_OUT: std::ffi::c_int = 2;
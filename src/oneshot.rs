@@ -0,0 +1,103 @@
+//! A single value channel whose receiving end is a future, used to turn the completion callbacks
+//! of the emscripten APIs into futures.
+//!
+//! The callbacks may run on another thread than the task awaiting the value, so the state is
+//! shared through an `Arc<Mutex>`.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+struct State<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+pub(crate) struct Sender<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+/// Resolves to the sent value, or to `None` when the sender was dropped without sending.
+pub(crate) struct Receiver<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+pub(crate) fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let state = Arc::new(Mutex::new(State {
+        value: None,
+        waker: None,
+        closed: false,
+    }));
+    (
+        Sender {
+            state: state.clone(),
+        },
+        Receiver { state },
+    )
+}
+
+impl<T> Sender<T> {
+    fn close(&self, value: Option<T>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if state.closed {
+                return;
+            }
+            state.closed = true;
+            state.value = value;
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn send(self, value: T) {
+        self.close(Some(value));
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.close(None);
+    }
+}
+
+impl<T> Future for Receiver<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            Poll::Ready(state.value.take())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn test_oneshot() {
+        let (sender, mut receiver) = channel();
+        assert_eq!(poll(&mut receiver), Poll::Pending);
+        sender.send(42);
+        assert_eq!(poll(&mut receiver), Poll::Ready(Some(42)));
+
+        let (sender, mut receiver) = channel::<i32>();
+        drop(sender);
+        assert_eq!(poll(&mut receiver), Poll::Ready(None));
+    }
+}
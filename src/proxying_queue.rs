@@ -0,0 +1,367 @@
+//! Safe helpers to run closures on other pthreads through the proxying queues of
+//! `emscripten/proxying.h`. Needs the `pthreads` feature.
+//!
+//! ```no_run
+//! use emscripten_rs_sys::{emscripten_main_runtime_thread_id, proxying_queue::*};
+//!
+//! let queue = ProxyingQueue::system();
+//! let main = unsafe { emscripten_main_runtime_thread_id() };
+//! let answer = queue.run_sync(main, || 6 * 7);
+//! ```
+
+use crate::*;
+use std::{
+    error::Error,
+    ffi::{c_int, c_void},
+    fmt,
+    future::Future,
+    ptr::NonNull,
+};
+
+/// An error returned when proxied work did not run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyError {
+    /// The work could not be queued. Returned by the methods that return once the work is queued,
+    /// [`ProxyingQueue::run_async`] and [`ProxyingQueue::run_with_callback`].
+    Failed,
+    /// The work did not run, as the target thread was not running or exited first. Returned by
+    /// the methods that wait for the work, for which emscripten reports both the same way.
+    Cancelled,
+}
+
+impl fmt::Display for ProxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => write!(f, "the work could not be queued"),
+            Self::Cancelled => write!(f, "the target thread did not run the work"),
+        }
+    }
+}
+
+impl Error for ProxyError {}
+
+/// The asynchronous proxying functions return 1 when the work was queued.
+fn check_queued(queued: c_int) -> Result<(), ProxyError> {
+    if queued == 1 {
+        Ok(())
+    } else {
+        Err(ProxyError::Failed)
+    }
+}
+
+/// The synchronous proxying functions return 1 when the work was done.
+fn check_done(done: c_int) -> Result<(), ProxyError> {
+    if done == 1 {
+        Ok(())
+    } else {
+        Err(ProxyError::Cancelled)
+    }
+}
+
+/// A closure passed to the proxying functions, with the slot of its result and the data of its
+/// completion callback.
+struct Task<F, R, C> {
+    f: Option<F>,
+    result: Option<R>,
+    then: C,
+}
+
+impl<F, R, C> Task<F, R, C> {
+    fn new(f: F, then: C) -> Self {
+        Self {
+            f: Some(f),
+            result: None,
+            then,
+        }
+    }
+}
+
+/// Runs the closure of a [`Task`] on the target thread.
+unsafe extern "C" fn run<F: FnOnce() -> R, R, C>(task: *mut c_void) {
+    let task = unsafe { &mut *(task as *mut Task<F, R, C>) };
+    let f = task.f.take().unwrap();
+    task.result = Some(f());
+}
+
+unsafe extern "C" fn run_once<F: FnOnce()>(f: *mut c_void) {
+    let f = unsafe { Box::from_raw(f as *mut F) };
+    f();
+}
+
+unsafe extern "C" fn run_with_ctx<F: FnOnce(ProxyingCtx)>(
+    ctx: *mut em_proxying_ctx,
+    f: *mut c_void,
+) {
+    let f = unsafe { (*(f as *mut Option<F>)).take().unwrap() };
+    f(ProxyingCtx(NonNull::new(ctx).unwrap()));
+}
+
+/// Calls the callback of [`ProxyingQueue::run_with_callback`] with the result of the closure.
+unsafe extern "C" fn callback<F, R, C: FnOnce(Option<R>)>(task: *mut c_void) {
+    let task = unsafe { Box::from_raw(task as *mut Task<F, R, C>) };
+    (task.then)(task.result);
+}
+
+/// Calls the callback of [`ProxyingQueue::run_with_callback`] when the target thread exited.
+unsafe extern "C" fn cancel<F, R, C: FnOnce(Option<R>)>(task: *mut c_void) {
+    let task = unsafe { Box::from_raw(task as *mut Task<F, R, C>) };
+    (task.then)(None);
+}
+
+type PromiseSender<R> = oneshot::Sender<Result<R, ProxyError>>;
+type PromiseTask<F, R> = Task<F, R, PromiseSender<R>>;
+
+unsafe extern "C" fn fulfilled<F, R>(
+    _: *mut *mut c_void,
+    task: *mut c_void,
+    _: *mut c_void,
+) -> em_promise_result_t {
+    let task = unsafe { Box::from_raw(task as *mut PromiseTask<F, R>) };
+    if let Some(result) = task.result {
+        task.then.send(Ok(result));
+    }
+    em_promise_result_t_EM_PROMISE_FULFILL
+}
+
+unsafe extern "C" fn rejected<F, R>(
+    _: *mut *mut c_void,
+    task: *mut c_void,
+    _: *mut c_void,
+) -> em_promise_result_t {
+    let task = unsafe { Box::from_raw(task as *mut PromiseTask<F, R>) };
+    task.then.send(Err(ProxyError::Cancelled));
+    em_promise_result_t_EM_PROMISE_FULFILL
+}
+
+/// A queue of work for other threads, an `em_proxying_queue`.
+///
+/// The work proxied to a thread runs when that thread returns to its event loop, or calls
+/// [`ProxyingQueue::execute`]. The [system queue](ProxyingQueue::system) is also executed while
+/// the thread waits on a futex or a mutex.
+pub struct ProxyingQueue {
+    queue: NonNull<em_proxying_queue>,
+    owned: bool,
+}
+
+unsafe impl Send for ProxyingQueue {}
+unsafe impl Sync for ProxyingQueue {}
+
+impl Default for ProxyingQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProxyingQueue {
+    /// Creates a queue with `em_proxying_queue_create`, destroyed when dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the queue could not be allocated.
+    pub fn new() -> Self {
+        let queue = NonNull::new(unsafe { em_proxying_queue_create() });
+        Self {
+            queue: queue.expect("could not create a proxying queue"),
+            owned: true,
+        }
+    }
+
+    /// The queue used by emscripten itself, see `emscripten_proxy_get_system_queue`.
+    pub fn system() -> Self {
+        Self {
+            queue: NonNull::new(unsafe { emscripten_proxy_get_system_queue() }).unwrap(),
+            owned: false,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut em_proxying_queue {
+        self.queue.as_ptr()
+    }
+
+    /// Runs the work proxied to the calling thread on this queue.
+    pub fn execute(&self) {
+        unsafe { emscripten_proxy_execute_queue(self.as_ptr()) };
+    }
+
+    /// Queues `f` to run on `thread` and returns right away.
+    pub fn run_async<F: FnOnce() + Send + 'static>(
+        &self,
+        thread: pthread_t,
+        f: F,
+    ) -> Result<(), ProxyError> {
+        let f = Box::into_raw(Box::new(f));
+        let queued = unsafe {
+            emscripten_proxy_async(self.as_ptr(), thread, Some(run_once::<F>), f as *mut c_void)
+        };
+        check_queued(queued).inspect_err(|_| drop(unsafe { Box::from_raw(f) }))
+    }
+
+    /// Runs `f` on `thread` and blocks until it returned, so it can borrow from the calling thread.
+    ///
+    /// The calling thread must not be `thread` itself, or a thread that `thread` waits for.
+    pub fn run_sync<R: Send, F: FnOnce() -> R + Send>(
+        &self,
+        thread: pthread_t,
+        f: F,
+    ) -> Result<R, ProxyError> {
+        let mut task = Task::new(f, ());
+        let done = unsafe {
+            emscripten_proxy_sync(
+                self.as_ptr(),
+                thread,
+                Some(run::<F, R, ()>),
+                &mut task as *mut _ as *mut c_void,
+            )
+        };
+        check_done(done)?;
+        task.result.ok_or(ProxyError::Cancelled)
+    }
+
+    /// Runs `f` on `thread` and blocks until the [`ProxyingCtx`] it receives is finished, which
+    /// can happen after `f` returned, e.g. in a callback of an asynchronous JS API.
+    pub fn run_sync_with_ctx<F: FnOnce(ProxyingCtx) + Send>(
+        &self,
+        thread: pthread_t,
+        f: F,
+    ) -> Result<(), ProxyError> {
+        let mut f = Some(f);
+        let done = unsafe {
+            emscripten_proxy_sync_with_ctx(
+                self.as_ptr(),
+                thread,
+                Some(run_with_ctx::<F>),
+                &mut f as *mut _ as *mut c_void,
+            )
+        };
+        check_done(done)
+    }
+
+    /// Queues `f` to run on `thread`, then `then` to run on the calling thread with its result,
+    /// or with `None` if `thread` exited before running `f`.
+    pub fn run_with_callback<R, F, C>(
+        &self,
+        thread: pthread_t,
+        f: F,
+        then: C,
+    ) -> Result<(), ProxyError>
+    where
+        R: Send + 'static,
+        F: FnOnce() -> R + Send + 'static,
+        C: FnOnce(Option<R>) + Send + 'static,
+    {
+        let task = Box::into_raw(Box::new(Task::<F, R, C>::new(f, then)));
+        let queued = unsafe {
+            emscripten_proxy_callback(
+                self.as_ptr(),
+                thread,
+                Some(run::<F, R, C>),
+                Some(callback::<F, R, C>),
+                Some(cancel::<F, R, C>),
+                task as *mut c_void,
+            )
+        };
+        check_queued(queued).inspect_err(|_| drop(unsafe { Box::from_raw(task) }))
+    }
+
+    /// Queues `f` to run on `thread`, with `emscripten_proxy_promise`, and resolves to its result.
+    /// The promise is rejected both when `f` could not be queued and when `thread` exited before
+    /// running it, which resolves to [`ProxyError::Cancelled`].
+    ///
+    /// The promise settles on the calling thread, so the future completes once that thread
+    /// returns to its event loop.
+    pub fn run_promise<R, F>(
+        &self,
+        thread: pthread_t,
+        f: F,
+    ) -> impl Future<Output = Result<R, ProxyError>> + use<R, F>
+    where
+        R: Send + 'static,
+        F: FnOnce() -> R + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let task = Box::into_raw(Box::new(PromiseTask::<F, R>::new(f, sender))) as *mut c_void;
+        unsafe {
+            let promise = emscripten_proxy_promise(
+                self.as_ptr(),
+                thread,
+                Some(run::<F, R, PromiseSender<R>>),
+                task,
+            );
+            // The promise is rejected without running `f` when it could not be queued, the
+            // callbacks own the task from now on.
+            let then = emscripten_promise_then(
+                promise,
+                Some(fulfilled::<F, R>),
+                Some(rejected::<F, R>),
+                task,
+            );
+            emscripten_promise_destroy(then);
+            emscripten_promise_destroy(promise);
+        }
+        async move { receiver.await.unwrap_or(Err(ProxyError::Cancelled)) }
+    }
+}
+
+impl Drop for ProxyingQueue {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { em_proxying_queue_destroy(self.as_ptr()) };
+        }
+    }
+}
+
+/// The context of the work started with [`ProxyingQueue::run_sync_with_ctx`], whose proxying
+/// thread stays blocked until it is finished.
+///
+/// Dropping the context finishes it too.
+pub struct ProxyingCtx(NonNull<em_proxying_ctx>);
+
+unsafe impl Send for ProxyingCtx {}
+
+impl ProxyingCtx {
+    pub fn as_ptr(&self) -> *mut em_proxying_ctx {
+        self.0.as_ptr()
+    }
+
+    /// Unblocks the proxying thread, with `emscripten_proxy_finish`.
+    pub fn finish(self) {}
+}
+
+impl Drop for ProxyingCtx {
+    fn drop(&mut self) {
+        unsafe { emscripten_proxy_finish(self.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn test_task() {
+        type Callback = Box<dyn FnOnce(Option<i32>)>;
+
+        let f: fn() -> i32 = || 6 * 7;
+        let mut task = Task::new(f, ());
+        unsafe { run::<fn() -> i32, i32, ()>(&mut task as *mut _ as *mut c_void) };
+        assert_eq!(task.result, Some(42));
+
+        let value = Rc::new(Cell::new(None));
+        let sent = value.clone();
+        let then: Callback = Box::new(move |result| sent.set(result));
+        let task = Box::into_raw(Box::new(Task::<_, i32, Callback>::new(f, then))) as *mut c_void;
+        unsafe { run::<fn() -> i32, i32, Callback>(task) };
+        assert_eq!(value.get(), None);
+        unsafe { callback::<fn() -> i32, i32, Callback>(task) };
+        assert_eq!(value.get(), Some(42));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(check_queued(1), Ok(()));
+        assert_eq!(check_queued(0), Err(ProxyError::Failed));
+        assert_eq!(check_done(1), Ok(()));
+        assert_eq!(check_done(0), Err(ProxyError::Cancelled));
+    }
+}
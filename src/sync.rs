@@ -0,0 +1,496 @@
+//! Synchronization primitives over the locks, semaphores and condition variables of the Wasm
+//! Workers API, which also work between wasm workers where pthread mutexes do not. Needs the
+//! `wasm_workers` feature.
+//!
+//! The main browser thread is not allowed to block with `Atomics.wait`, so the blocking calls
+//! busy-spin there and wait with `Atomics.wait` on every other thread. The `_async` variants
+//! return futures instead, built on `Atomics.waitAsync`, which never block.
+
+use crate::{
+    atomic_wait::{AsyncWait, finish, finish_volatile},
+    *,
+};
+use std::{
+    cell::UnsafeCell,
+    error::Error,
+    ffi::c_int,
+    fmt,
+    future::Future,
+    mem,
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+/// The error of the waits that gave up after their timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the wait timed out")
+    }
+}
+
+impl Error for TimedOut {}
+
+fn is_main_browser_thread() -> bool {
+    unsafe { emscripten_is_main_browser_thread() != 0 }
+}
+
+fn nanos(timeout: Duration) -> i64 {
+    timeout.as_nanos().min(i64::MAX as u128) as i64
+}
+
+fn millis(timeout: Duration) -> f64 {
+    timeout.as_secs_f64() * 1000.0
+}
+
+/// The `maxWaitMilliseconds` of the async waits, where `None` waits forever.
+fn max_wait(timeout: Option<Duration>) -> f64 {
+    timeout.map_or(f64::INFINITY, millis)
+}
+
+/// Spins until `f` returns `true` or `timeout` elapses, and returns whether `f` succeeded.
+fn spin_until(timeout: Option<Duration>, mut f: impl FnMut() -> bool) -> bool {
+    let deadline = timeout.map(|timeout| unsafe { emscripten_get_now() } + millis(timeout));
+    loop {
+        if f() {
+            return true;
+        }
+        if deadline.is_some_and(|deadline| unsafe { emscripten_get_now() } >= deadline) {
+            return false;
+        }
+        std::hint::spin_loop();
+    }
+}
+
+/// A mutual exclusion lock over an `emscripten_lock_t`.
+pub struct EmMutex<T: ?Sized> {
+    lock: AtomicU32,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for EmMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for EmMutex<T> {}
+
+impl<T> EmMutex<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            lock: AtomicU32::new(EMSCRIPTEN_LOCK_T_STATIC_INITIALIZER),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> EmMutex<T> {
+    fn raw(&self) -> *mut u32 {
+        self.lock.as_ptr()
+    }
+
+    /// Acquires the lock, busy-spinning on the main browser thread.
+    pub fn lock(&self) -> EmMutexGuard<'_, T> {
+        unsafe {
+            if is_main_browser_thread() {
+                emscripten_lock_busyspin_waitinf_acquire(self.raw());
+            } else {
+                emscripten_lock_waitinf_acquire(self.raw());
+            }
+        }
+        EmMutexGuard { mutex: self }
+    }
+
+    /// Acquires the lock, or returns `None` if it is still held after `timeout`.
+    pub fn lock_timeout(&self, timeout: Duration) -> Option<EmMutexGuard<'_, T>> {
+        let acquired = unsafe {
+            if is_main_browser_thread() {
+                emscripten_lock_busyspin_wait_acquire(self.raw(), millis(timeout))
+            } else {
+                emscripten_lock_wait_acquire(self.raw(), nanos(timeout))
+            }
+        };
+        acquired.then_some(EmMutexGuard { mutex: self })
+    }
+
+    pub fn try_lock(&self) -> Option<EmMutexGuard<'_, T>> {
+        unsafe { emscripten_lock_try_acquire(self.raw()) }.then_some(EmMutexGuard { mutex: self })
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: ?Sized + 'static> EmMutex<T> {
+    /// Acquires the lock without blocking the thread, with `emscripten_lock_async_acquire`.
+    ///
+    /// The lock is acquired by a callback that runs when the thread returns to its event loop, so
+    /// the future must be awaited on the thread that created it. The pending acquisition cannot
+    /// be cancelled, dropping the future releases the lock once the callback took it, which is why
+    /// the callback keeps a clone of the `Arc`.
+    pub fn lock_async(
+        self: &Arc<Self>,
+        timeout: Option<Duration>,
+    ) -> impl Future<Output = Result<OwnedEmMutexGuard<T>, TimedOut>> + use<T> {
+        let lock = self.raw();
+        let mutex = self.clone();
+        let wait = AsyncWait::start(
+            move || unsafe { emscripten_lock_release(mutex.raw()) },
+            |user| {
+                unsafe {
+                    emscripten_lock_async_acquire(
+                        lock,
+                        Some(finish_volatile),
+                        user,
+                        max_wait(timeout),
+                    )
+                };
+                None
+            },
+        );
+        let mutex = self.clone();
+        async move {
+            match wait.await {
+                ATOMICS_WAIT_OK => Ok(OwnedEmMutexGuard { mutex }),
+                _ => Err(TimedOut),
+            }
+        }
+    }
+}
+
+impl<T: Default> Default for EmMutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Releases the lock of an [`EmMutex`] when dropped.
+pub struct EmMutexGuard<'a, T: ?Sized> {
+    mutex: &'a EmMutex<T>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for EmMutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for EmMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for EmMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for EmMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { emscripten_lock_release(self.mutex.raw()) };
+    }
+}
+
+/// Releases the lock of an [`EmMutex`] in an `Arc` when dropped, see [`EmMutex::lock_async`].
+pub struct OwnedEmMutexGuard<T: ?Sized> {
+    mutex: Arc<EmMutex<T>>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for OwnedEmMutexGuard<T> {}
+
+impl<T: ?Sized> OwnedEmMutexGuard<T> {
+    /// The mutex the guard locks.
+    pub fn mutex(&self) -> &Arc<EmMutex<T>> {
+        &self.mutex
+    }
+
+    /// Takes the mutex out of the guard without releasing the lock.
+    fn into_locked_mutex(self) -> Arc<EmMutex<T>> {
+        let guard = mem::ManuallyDrop::new(self);
+        unsafe { std::ptr::read(&guard.mutex) }
+    }
+}
+
+impl<T: ?Sized> Deref for OwnedEmMutexGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedEmMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for OwnedEmMutexGuard<T> {
+    fn drop(&mut self) {
+        unsafe { emscripten_lock_release(self.mutex.raw()) };
+    }
+}
+
+/// A counting semaphore over an `emscripten_semaphore_t`.
+pub struct EmSemaphore {
+    count: AtomicU32,
+}
+
+impl EmSemaphore {
+    pub const fn new(count: u32) -> Self {
+        Self {
+            count: AtomicU32::new(count),
+        }
+    }
+
+    fn raw(&self) -> *mut u32 {
+        self.count.as_ptr()
+    }
+
+    /// The number of resources currently available.
+    pub fn count(&self) -> u32 {
+        self.count.load(Ordering::Acquire)
+    }
+
+    /// Takes `n` resources if they are available.
+    pub fn try_acquire(&self, n: u32) -> bool {
+        unsafe { emscripten_semaphore_try_acquire(self.raw(), n as c_int) >= 0 }
+    }
+
+    /// Waits until `n` resources are available and takes them, busy-spinning on the main browser
+    /// thread.
+    pub fn acquire(&self, n: u32) {
+        if is_main_browser_thread() {
+            spin_until(None, || self.try_acquire(n));
+        } else {
+            unsafe { emscripten_semaphore_waitinf_acquire(self.raw(), n as c_int) };
+        }
+    }
+
+    /// Like [`EmSemaphore::acquire`], but gives up after `timeout` and returns `false`.
+    pub fn acquire_timeout(&self, n: u32, timeout: Duration) -> bool {
+        if is_main_browser_thread() {
+            spin_until(Some(timeout), || self.try_acquire(n))
+        } else {
+            unsafe {
+                emscripten_semaphore_wait_acquire(self.raw(), n as c_int, nanos(timeout)) >= 0
+            }
+        }
+    }
+
+    /// Takes `n` resources without blocking the thread, with `emscripten_semaphore_async_acquire`.
+    ///
+    /// Like [`EmMutex::lock_async`], dropping the future gives the resources back once the
+    /// pending acquisition took them, with a clone of the `Arc`.
+    pub fn acquire_async(
+        self: &Arc<Self>,
+        n: u32,
+        timeout: Option<Duration>,
+    ) -> impl Future<Output = Result<(), TimedOut>> + use<> {
+        let sem = self.raw();
+        let semaphore = self.clone();
+        let wait = AsyncWait::start(
+            move || {
+                semaphore.release(n);
+            },
+            |user| {
+                unsafe {
+                    emscripten_semaphore_async_acquire(
+                        sem,
+                        n as c_int,
+                        Some(finish_volatile),
+                        user,
+                        max_wait(timeout),
+                    )
+                };
+                None
+            },
+        );
+        async move {
+            match wait.await {
+                ATOMICS_WAIT_OK => Ok(()),
+                _ => Err(TimedOut),
+            }
+        }
+    }
+
+    /// Gives back `n` resources, waking the waiters, and returns the count before the release.
+    pub fn release(&self, n: u32) -> u32 {
+        unsafe { emscripten_semaphore_release(self.raw(), n as c_int) }
+    }
+}
+
+/// A condition variable over an `emscripten_condvar_t`, used with an [`EmMutex`].
+pub struct EmCondvar {
+    condvar: AtomicU32,
+}
+
+impl Default for EmCondvar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmCondvar {
+    pub const fn new() -> Self {
+        Self {
+            condvar: AtomicU32::new(0),
+        }
+    }
+
+    fn raw(&self) -> *mut u32 {
+        self.condvar.as_ptr()
+    }
+
+    /// Releases the lock of `guard`, waits for a notification, and reacquires the lock.
+    ///
+    /// Like every condition variable, it can wake up spuriously, so the condition must be checked
+    /// again in a loop.
+    pub fn wait<'a, T: ?Sized>(&self, guard: EmMutexGuard<'a, T>) -> EmMutexGuard<'a, T> {
+        if is_main_browser_thread() {
+            self.spin_wait(guard, None).0
+        } else {
+            unsafe { emscripten_condvar_waitinf(self.raw(), guard.mutex.raw()) };
+            guard
+        }
+    }
+
+    /// Like [`EmCondvar::wait`], but gives up after `timeout`. Also returns whether the wait
+    /// timed out.
+    pub fn wait_timeout<'a, T: ?Sized>(
+        &self,
+        guard: EmMutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> (EmMutexGuard<'a, T>, bool) {
+        if is_main_browser_thread() {
+            self.spin_wait(guard, Some(timeout))
+        } else {
+            let notified =
+                unsafe { emscripten_condvar_wait(self.raw(), guard.mutex.raw(), nanos(timeout)) };
+            (guard, !notified)
+        }
+    }
+
+    /// Releases the lock of `guard` and waits for a notification without blocking the thread, with
+    /// `emscripten_condvar_wait_async`, then reacquires the lock with [`EmMutex::lock_async`].
+    ///
+    /// Resolves to the guard, and to [`TimedOut`] when no notification came within `timeout`.
+    /// Dropping the future while it waits for a notification cancels the wait.
+    pub fn wait_async<T: ?Sized + 'static>(
+        &self,
+        guard: OwnedEmMutexGuard<T>,
+        timeout: Option<Duration>,
+    ) -> impl Future<Output = (OwnedEmMutexGuard<T>, Result<(), TimedOut>)> + use<'_, T> {
+        // `emscripten_condvar_wait_async` releases the lock.
+        let mutex = guard.into_locked_mutex();
+        let condvar = self.raw();
+        let wait = AsyncWait::start(
+            || {},
+            |user| {
+                Some(unsafe {
+                    emscripten_condvar_wait_async(
+                        condvar,
+                        mutex.raw(),
+                        Some(finish),
+                        user,
+                        max_wait(timeout),
+                    )
+                })
+            },
+        );
+        async move {
+            let result = match wait.await {
+                ATOMICS_WAIT_TIMED_OUT => Err(TimedOut),
+                _ => Ok(()),
+            };
+            let guard = match mutex.lock_async(None).await {
+                Ok(guard) => guard,
+                Err(TimedOut) => unreachable!("waited for the lock without a timeout"),
+            };
+            (guard, result)
+        }
+    }
+
+    /// Emulates `emscripten_condvar_wait` with busy-spinning: each notification increments the
+    /// condition variable.
+    fn spin_wait<'a, T: ?Sized>(
+        &self,
+        guard: EmMutexGuard<'a, T>,
+        timeout: Option<Duration>,
+    ) -> (EmMutexGuard<'a, T>, bool) {
+        let mutex = guard.mutex;
+        let generation = self.condvar.load(Ordering::Acquire);
+        drop(guard);
+        let notified = spin_until(timeout, || {
+            self.condvar.load(Ordering::Acquire) != generation
+        });
+        (mutex.lock(), !notified)
+    }
+
+    /// Wakes up one waiting thread.
+    pub fn notify_one(&self) {
+        unsafe { emscripten_condvar_signal(self.raw(), 1) };
+    }
+
+    /// Wakes up every waiting thread.
+    pub fn notify_all(&self) {
+        unsafe { emscripten_condvar_signal(self.raw(), EMSCRIPTEN_NOTIFY_ALL_WAITERS as i64) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static COUNTER: EmMutex<u32> = EmMutex::new(0);
+
+    #[test]
+    fn test_mutex() {
+        *COUNTER.lock() += 1;
+        let guard = COUNTER.try_lock().unwrap();
+        assert_eq!(*guard, 1);
+        assert!(COUNTER.try_lock().is_none());
+        drop(guard);
+        assert!(COUNTER.lock_timeout(Duration::from_millis(1)).is_some());
+    }
+
+    #[test]
+    fn test_semaphore() {
+        let semaphore = EmSemaphore::new(2);
+        assert!(semaphore.try_acquire(2));
+        assert!(!semaphore.try_acquire(1));
+        assert!(!semaphore.acquire_timeout(1, Duration::from_millis(1)));
+        assert_eq!(semaphore.release(1), 0);
+        semaphore.acquire(1);
+        assert_eq!(semaphore.count(), 0);
+    }
+
+    #[test]
+    fn test_lock_async() {
+        let mutex = Arc::new(EmMutex::new(0));
+        let future = mutex.lock_async(None);
+        // The pending acquisition keeps the mutex alive after the future is dropped.
+        assert!(Arc::strong_count(&mutex) > 1);
+        drop(future);
+        let semaphore = Arc::new(EmSemaphore::new(0));
+        drop(semaphore.acquire_async(1, Some(Duration::from_millis(1))));
+        assert!(Arc::strong_count(&semaphore) > 1);
+    }
+
+    #[test]
+    fn test_condvar_timeout() {
+        let mutex = EmMutex::new(());
+        let condvar = EmCondvar::new();
+        let (guard, timed_out) = condvar.wait_timeout(mutex.lock(), Duration::from_millis(1));
+        assert!(timed_out);
+        drop(guard);
+        assert!(mutex.try_lock().is_some());
+    }
+}
@@ -0,0 +1,2 @@
+// Bodies included with include_js! are embedded unchanged.
+return a + b;
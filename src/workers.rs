@@ -0,0 +1,330 @@
+//! Safe helpers for the Wasm Workers API. Needs the `wasm_workers` feature, which links with
+//! `-sWASM_WORKERS`.
+//!
+//! [`WasmWorker::spawn`] runs a closure on a new worker. [`post`] runs a closure on another wasm
+//! worker or on an audio worklet thread, through the `post_function` family of functions, and
+//! `post_args` calls a function with typed arguments.
+//!
+//! ```no_run
+//! use emscripten_rs_sys::workers::*;
+//!
+//! async fn sum() -> u64 {
+//!     let worker = WasmWorker::spawn(64 * 1024, || (0..1_000_000u64).sum::<u64>());
+//!     worker.join().await
+//! }
+//! ```
+
+use crate::*;
+use std::{
+    ffi::{c_char, c_int, c_void},
+    marker::PhantomData,
+};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// A thread that functions can be posted to, with the `post_function` family of its API.
+///
+/// The trait is sealed, as the `post_function` pointers must match the id.
+pub trait PostTarget: sealed::Sealed {
+    /// The id passed to the `post_function` functions.
+    fn post_id(&self) -> c_int;
+
+    #[doc(hidden)]
+    const POST_VI: unsafe extern "C" fn(c_int, Option<unsafe extern "C" fn(c_int)>, c_int);
+
+    #[doc(hidden)]
+    const POST_SIG: unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...);
+}
+
+/// The id of a wasm worker, see `emscripten_wasm_worker_self_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WasmWorkerId(pub c_int);
+
+impl WasmWorkerId {
+    /// The thread that created the calling worker.
+    pub const PARENT: Self = Self(EMSCRIPTEN_WASM_WORKER_ID_PARENT as c_int);
+}
+
+impl sealed::Sealed for WasmWorkerId {}
+
+impl PostTarget for WasmWorkerId {
+    fn post_id(&self) -> c_int {
+        self.0
+    }
+
+    const POST_VI: unsafe extern "C" fn(c_int, Option<unsafe extern "C" fn(c_int)>, c_int) =
+        emscripten_wasm_worker_post_function_vi;
+
+    const POST_SIG: unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...) =
+        emscripten_wasm_worker_post_function_sig;
+}
+
+/// Whether the calling thread is a wasm worker.
+pub fn is_wasm_worker() -> bool {
+    unsafe { emscripten_current_thread_is_wasm_worker() }
+}
+
+/// The id of the calling wasm worker, `None` on the main thread and on pthreads.
+pub fn self_id() -> Option<WasmWorkerId> {
+    match unsafe { emscripten_wasm_worker_self_id() } {
+        0 => None,
+        id => Some(WasmWorkerId(id as c_int)),
+    }
+}
+
+/// Terminates every wasm worker created by the calling thread.
+pub fn terminate_all() {
+    unsafe { emscripten_terminate_all_wasm_workers() };
+}
+
+/// A handle to a wasm worker started with [`WasmWorker::spawn`].
+///
+/// Dropping the handle detaches the worker, which keeps running. The handle stays on the thread
+/// that spawned the worker, as only that thread can post to it.
+pub struct WasmWorker<T> {
+    id: WasmWorkerId,
+    result: oneshot::Receiver<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<T: Send + 'static> WasmWorker<T> {
+    /// Creates a worker with a stack of `stack_size` bytes and runs `f` on it.
+    ///
+    /// The stack and the thread local storage of the worker are allocated together with
+    /// `emscripten_malloc_wasm_worker`, and stay allocated after the worker finishes, as a worker
+    /// can still run functions posted to it.
+    ///
+    /// # Panics
+    ///
+    /// If the worker could not be created.
+    pub fn spawn<F: FnOnce() -> T + Send + 'static>(stack_size: usize, f: F) -> Self {
+        let id = unsafe { emscripten_malloc_wasm_worker(stack_size) };
+        assert!(id != 0, "could not create a wasm worker");
+        let id = WasmWorkerId(id);
+        let (sender, result) = oneshot::channel();
+        post(&id, move || {
+            let value = f();
+            // Wakers are not necessarily thread safe, the spawning thread completes the future.
+            post(&WasmWorkerId::PARENT, move || sender.send(value));
+        });
+        Self {
+            id,
+            result,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl<T> WasmWorker<T> {
+    pub fn id(&self) -> WasmWorkerId {
+        self.id
+    }
+
+    /// Runs `f` on the worker, after the closure it was spawned with.
+    pub fn post<F: FnOnce() + Send + 'static>(&self, f: F) {
+        post(&self.id, f);
+    }
+
+    /// Stops the worker right away, even in the middle of the closure it runs.
+    pub fn terminate(self) {
+        unsafe { emscripten_terminate_wasm_worker(self.id.0) };
+    }
+
+    /// Resolves to the value returned by the closure of the worker.
+    ///
+    /// The result is posted back to the spawning thread, so the future completes once that
+    /// thread returns to its event loop.
+    pub async fn join(self) -> T {
+        self.result
+            .await
+            .expect("the wasm worker dropped its closure without returning")
+    }
+}
+
+impl<T> sealed::Sealed for WasmWorker<T> {}
+
+impl<T> PostTarget for WasmWorker<T> {
+    fn post_id(&self) -> c_int {
+        self.id.0
+    }
+
+    const POST_VI: unsafe extern "C" fn(c_int, Option<unsafe extern "C" fn(c_int)>, c_int) =
+        WasmWorkerId::POST_VI;
+
+    const POST_SIG: unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...) =
+        WasmWorkerId::POST_SIG;
+}
+
+/// Runs `f` on the thread `target` the next time it returns to its event loop.
+///
+/// The closure is boxed and its pointer posted with the `_vi` variant. It is leaked if the target
+/// terminates before running it.
+pub fn post<T: PostTarget, F: FnOnce() + Send + 'static>(target: &T, f: F) {
+    unsafe extern "C" fn run<F: FnOnce()>(f: c_int) {
+        let f = unsafe { Box::from_raw(f as usize as *mut F) };
+        f();
+    }
+
+    let f = Box::into_raw(Box::new(f));
+    unsafe { T::POST_VI(target.post_id(), Some(run::<F>), f as usize as c_int) };
+}
+
+/// Arguments of a function posted with [`post_args`], whose `_sig` signature string is derived
+/// from their types.
+#[cfg(feature = "em_js")]
+pub trait PostArgs: asm::AsmArgs {
+    /// The type of the functions taking these arguments.
+    type Fn: Copy;
+
+    #[doc(hidden)]
+    unsafe fn post<T: PostTarget>(self, target: &T, f: Self::Fn);
+}
+
+#[cfg(feature = "em_js")]
+macro_rules! impl_post_args {
+    ($($arg:ident)*) => {
+        impl<$($arg: asm::AsmAbi),*> PostArgs for ($($arg,)*) {
+            type Fn = unsafe extern "C" fn($($arg),*);
+
+            #[allow(non_snake_case)]
+            unsafe fn post<T: PostTarget>(self, target: &T, f: Self::Fn) {
+                let ($($arg,)*) = self;
+                unsafe {
+                    T::POST_SIG(
+                        target.post_id(),
+                        f as *mut c_void,
+                        <Self as asm::AsmArgs>::SIG.as_ptr() as *const c_char,
+                        $($arg),*
+                    )
+                };
+            }
+        }
+    };
+}
+
+#[cfg(feature = "em_js")]
+impl_post_args!(A);
+#[cfg(feature = "em_js")]
+impl_post_args!(A B);
+#[cfg(feature = "em_js")]
+impl_post_args!(A B C);
+#[cfg(feature = "em_js")]
+impl_post_args!(A B C D);
+#[cfg(feature = "em_js")]
+impl_post_args!(A B C D E);
+#[cfg(feature = "em_js")]
+impl_post_args!(A B C D E F);
+
+/// Calls `f` with `args` on the thread `target`, without allocating. The signature string passed
+/// to the `_sig` variant is built from the argument types at compile time, e.g. `"ijd"` for
+/// `(i32, i64, f64)`.
+///
+/// # Safety
+///
+/// Pointers among the arguments must stay valid until the target thread runs `f`.
+#[cfg(feature = "em_js")]
+pub unsafe fn post_args<T: PostTarget, A: PostArgs>(target: &T, f: A::Fn, args: A) {
+    unsafe { args.post(target, f) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "em_js")]
+    use std::sync::atomic::{AtomicI64, AtomicU64};
+    use std::{
+        ffi::CStr,
+        sync::{
+            Arc,
+            atomic::{AtomicI32, Ordering},
+        },
+    };
+
+    /// Runs posted functions right away.
+    struct Inline;
+
+    unsafe extern "C" fn call_now(_: c_int, f: Option<unsafe extern "C" fn(c_int)>, arg: c_int) {
+        unsafe { f.unwrap()(arg) };
+    }
+
+    /// Calls a function posted with the `ijd` signature right away. The wasm C ABI passes the
+    /// variadic arguments as a pointer to a buffer holding each one at its natural alignment.
+    unsafe extern "C" fn call_sig_now(
+        _: c_int,
+        f: *mut c_void,
+        sig: *const c_char,
+        args: *const u8,
+    ) {
+        assert_eq!(unsafe { CStr::from_ptr(sig) }, c"ijd");
+        let f =
+            unsafe { std::mem::transmute::<*mut c_void, unsafe extern "C" fn(c_int, i64, f64)>(f) };
+        unsafe {
+            f(
+                (args as *const c_int).read(),
+                (args.add(8) as *const i64).read(),
+                (args.add(16) as *const f64).read(),
+            )
+        };
+    }
+
+    impl sealed::Sealed for Inline {}
+
+    impl PostTarget for Inline {
+        fn post_id(&self) -> c_int {
+            0
+        }
+
+        const POST_VI: unsafe extern "C" fn(c_int, Option<unsafe extern "C" fn(c_int)>, c_int) =
+            call_now;
+
+        // On wasm a variadic function takes the same parameters as one with a trailing pointer.
+        const POST_SIG: unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...) = unsafe {
+            std::mem::transmute::<
+                unsafe extern "C" fn(c_int, *mut c_void, *const c_char, *const u8),
+                unsafe extern "C" fn(c_int, *mut c_void, *const c_char, ...),
+            >(call_sig_now)
+        };
+    }
+
+    #[test]
+    fn test_post() {
+        let value = Arc::new(AtomicI32::new(0));
+        let sent = value.clone();
+        post(&Inline, move || sent.store(42, Ordering::Relaxed));
+        assert_eq!(value.load(Ordering::Relaxed), 42);
+    }
+
+    #[test]
+    fn test_main_thread() {
+        assert!(!is_wasm_worker());
+        assert_eq!(self_id(), None);
+    }
+
+    #[cfg(feature = "em_js")]
+    #[test]
+    fn test_post_args_signature() {
+        assert_eq!(<(c_int, i64, f64) as asm::AsmArgs>::SIG, b"ijd\0");
+    }
+
+    #[cfg(feature = "em_js")]
+    #[test]
+    fn test_post_args() {
+        static INT: AtomicI32 = AtomicI32::new(0);
+        static LONG: AtomicI64 = AtomicI64::new(0);
+        static DOUBLE: AtomicU64 = AtomicU64::new(0);
+
+        unsafe extern "C" fn store(int: c_int, long: i64, double: f64) {
+            INT.store(int, Ordering::Relaxed);
+            LONG.store(long, Ordering::Relaxed);
+            DOUBLE.store(double.to_bits(), Ordering::Relaxed);
+        }
+
+        let store = store as unsafe extern "C" fn(c_int, i64, f64);
+        unsafe { post_args(&Inline, store, (7, -1 << 40, 0.5)) };
+        assert_eq!(INT.load(Ordering::Relaxed), 7);
+        assert_eq!(LONG.load(Ordering::Relaxed), -1 << 40);
+        assert_eq!(f64::from_bits(DOUBLE.load(Ordering::Relaxed)), 0.5);
+    }
+}